@@ -0,0 +1,90 @@
+## 메인 메뉴
+
+menu-start = Start Game
+menu-campaign = Campaign
+menu-survival = Survival
+menu-practice = Practice
+menu-difficulty = Difficulty: { $level ->
+    [easy] Easy
+    [hard] Hard
+    [insane] Insane
+   *[normal] Normal
+}
+menu-adaptive = Adaptive: { $enabled ->
+    [on] On
+   *[off] Off
+}
+menu-movement = Movement: { $preset ->
+    [vertical] Vertical
+   *[free] Free
+}
+menu-players = Players: { $mode ->
+    [keyboard] 2 (Keyboard)
+    [gamepads] 2 (Gamepads)
+   *[solo] 1
+}
+menu-shared-lives = Shared Lives: { $enabled ->
+    [on] On
+   *[off] Off
+}
+menu-language = Language: { $language ->
+    [ko] Korean
+   *[en] English
+}
+menu-exit = Exit
+common-back = Back
+
+## 스테이지 선택 / 연습
+
+stage-select-title = Select Stage
+stage-locked = Stage { $number } - Locked
+stage-entry = Stage { $number } - { $name }
+stage-entry-best = Stage { $number } - { $name }  (Best { $best })
+stage-name-outskirts = Outskirts
+stage-name-nebula = Nebula
+stage-name-core = Core
+practice-title = Practice - Start From
+practice-help = Infinite HP   H: hitboxes   R: rewind   Esc: quit
+practice-hud = PRACTICE   H: hitboxes   R: rewind 3s   Esc: quit
+
+## 스테이지 클리어
+
+stage-clear-title = Stage { $number } Clear - { $name }
+stage-clear-stats =
+    Kills      { $kills }
+    Accuracy   { $accuracy }%
+    Damage     { $damage }
+    Time       { $time }
+    Score      { $score }
+stage-clear-unlocked = Next stage unlocked!
+stage-clear-hint-next = Enter: next stage    Esc: stage select
+stage-clear-hint-complete = Campaign complete!    Enter: stage select
+
+## 게임 오버 / 생존 기록
+
+game-over =
+    Game Over
+    Press Enter to return
+survival-result = Survived { $time }  -  Score { $score }
+survival-new-record = New record! Rank #{ $rank }
+survival-no-record = No new record
+survival-records = --- Survival Records ---
+
+## 인게임
+
+pause-title = PAUSED
+pause-help = P / Esc: resume    Q: quit to menu
+hud-score = Score { $score }
+hud-player-score = { $player } { $score }
+continue-prompt =
+    Continue? { $seconds }
+    Enter: continue (score resets)
+    Esc: give up
+
+## 넷플레이
+
+net-you = { $player } (You)
+net-hud-player = { $marker }{ $player } { $score }  HP { $hp }
+net-hud-frame = Frame { $frame }  Rollbacks { $rollbacks } (last { $last })
+net-hud-waiting = Waiting for peer...
+net-hud-desync = DESYNC at frame { $frame }
@@ -0,0 +1,90 @@
+## 메인 메뉴
+
+menu-start = 게임 시작
+menu-campaign = 캠페인
+menu-survival = 생존 모드
+menu-practice = 연습 모드
+menu-difficulty = 난이도: { $level ->
+    [easy] 쉬움
+    [hard] 어려움
+    [insane] 극한
+   *[normal] 보통
+}
+menu-adaptive = 난이도 자동 조절: { $enabled ->
+    [on] 켬
+   *[off] 끔
+}
+menu-movement = 이동 방식: { $preset ->
+    [vertical] 상하
+   *[free] 자유
+}
+menu-players = 플레이어: { $mode ->
+    [keyboard] 2명 (키보드)
+    [gamepads] 2명 (게임패드)
+   *[solo] 1명
+}
+menu-shared-lives = 목숨 공유: { $enabled ->
+    [on] 켬
+   *[off] 끔
+}
+menu-language = 언어: { $language ->
+    [en] English
+   *[ko] 한국어
+}
+menu-exit = 종료
+common-back = 뒤로
+
+## 스테이지 선택 / 연습
+
+stage-select-title = 스테이지 선택
+stage-locked = 스테이지 { $number } - 잠김
+stage-entry = 스테이지 { $number } - { $name }
+stage-entry-best = 스테이지 { $number } - { $name }  (최고 { $best })
+stage-name-outskirts = 변두리
+stage-name-nebula = 성운
+stage-name-core = 중심핵
+practice-title = 연습 - 시작 지점
+practice-help = 무한 체력   H: 판정 표시   R: 되감기   Esc: 종료
+practice-hud = 연습   H: 판정 표시   R: 3초 되감기   Esc: 종료
+
+## 스테이지 클리어
+
+stage-clear-title = 스테이지 { $number } 클리어 - { $name }
+stage-clear-stats =
+    처치       { $kills }
+    명중률     { $accuracy }%
+    피격       { $damage }
+    시간       { $time }
+    점수       { $score }
+stage-clear-unlocked = 다음 스테이지가 열렸습니다!
+stage-clear-hint-next = Enter: 다음 스테이지    Esc: 스테이지 선택
+stage-clear-hint-complete = 캠페인 완료!    Enter: 스테이지 선택
+
+## 게임 오버 / 생존 기록
+
+game-over =
+    게임 오버
+    Enter를 눌러 돌아가기
+survival-result = 생존 { $time }  -  점수 { $score }
+survival-new-record = 신기록! { $rank }위
+survival-no-record = 신기록 없음
+survival-records = --- 생존 기록 ---
+
+## 인게임
+
+pause-title = 일시정지
+pause-help = P / Esc: 계속    Q: 메뉴로
+hud-score = 점수 { $score }
+hud-player-score = { $player } { $score }
+continue-prompt =
+    이어서 하시겠습니까? { $seconds }
+    Enter: 이어하기 (점수 초기화)
+    Esc: 포기
+
+## 넷플레이
+
+net-you = { $player } (나)
+net-hud-player = { $marker }{ $player } { $score }  HP { $hp }
+net-hud-frame = 프레임 { $frame }  롤백 { $rollbacks } (마지막 { $last })
+net-hud-waiting = 상대를 기다리는 중...
+net-hud-desync = 동기화 어긋남: { $frame } 프레임
@@ -0,0 +1,40 @@
+// 빌드 정보(버전, git describe, 빌드 프로파일)를 컴파일 타임 환경 변수로 심는다.
+// 세이브/크래시 로그에 기록해 어떤 빌드가 만든 파일인지 구분하기 위함
+use std::path::PathBuf;
+use std::process::Command;
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn main() {
+    let describe = git(&["describe", "--tags", "--always", "--dirty"])
+        .unwrap_or_else(|| "unknown".to_string());
+    let profile = std::env::var("PROFILE").unwrap_or_else(|_| "unknown".to_string());
+
+    println!("cargo:rustc-env=DRAGOON_GIT_DESCRIBE={describe}");
+    println!("cargo:rustc-env=DRAGOON_BUILD_PROFILE={profile}");
+
+    // 커밋/체크아웃/스테이징/태그 시 다시 실행되도록 git 메타데이터를 감시
+    // (브랜치 커밋은 refs/heads, 태그는 refs/tags, gc 뒤에는 packed-refs가 바뀐다)
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        let git_dir = PathBuf::from(git_dir);
+        for file in ["HEAD", "logs/HEAD", "index", "refs", "packed-refs"] {
+            let path = git_dir.join(file);
+            if path.exists() {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+    }
+    // --dirty 표시는 스테이징하지 않은 수정에도 달라지므로 소스도 감시
+    // (에셋은 핫 리로드 중 재컴파일을 부르지 않도록 제외)
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=engine/src");
+    println!("cargo:rerun-if-changed=build.rs");
+}
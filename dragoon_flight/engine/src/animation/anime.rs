@@ -5,6 +5,7 @@ use std::collections::HashMap;
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Component)]
 pub enum AnimationPlaybackState {
     Playing,
+    #[allow(dead_code)] // 아직 정지 상태로 전환하는 곳이 없음
     Stopped,
 }
 
@@ -0,0 +1,5 @@
+pub mod anime;
+pub mod plugin;
+
+pub use anime::{Animation, AnimationPlaybackState, FrameCache};
+pub use plugin::{AnimationPlugin, AnimationSet};
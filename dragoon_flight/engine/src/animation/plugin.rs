@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+use super::anime::{FrameCache, animate_sprite};
+
+// 프레임 애니메이션 시스템 묶음. 게임 쪽에서 실행 조건/순서를 정한다
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnimationSet;
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameCache>()
+            .add_systems(Update, animate_sprite.in_set(AnimationSet));
+    }
+}
@@ -1,17 +1,5 @@
-use bevy::prelude::*;
 use bevy::math::bounding::*;
-
-#[derive(Component)]
-pub struct Velocity2D {
-    pub x: f32,
-    pub y: f32,
-}
-
-#[derive(Component)]
-pub struct Acceleration2D {
-    pub x: f32,
-    pub y: f32,
-}
+use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct Collider {
@@ -62,7 +50,7 @@ impl CollisionCheck for (&Transform, &Collider) {
 
         let res = aabb1.intersects(&aabb2);
         if res {
-            info!("AABB hit: a1={:?}, a2={:?}", aabb1, aabb2);
+            trace!("AABB hit: a1={:?}, a2={:?}", aabb1, aabb2);
         }
         res
     }
@@ -100,7 +88,7 @@ pub fn auto_size_colliders_system(
         // 변경이 있는 경우에만 갱신
         if (new_scale.truncate() - collider.scale.truncate()).length_squared() > f32::EPSILON {
             collider.scale = new_scale;
-            info!(
+            trace!(
                 "Auto collider set: e={:?}, half=({:.1},{:.1})",
                 entity,
                 half.x,
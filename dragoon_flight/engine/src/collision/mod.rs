@@ -0,0 +1,5 @@
+pub mod collider;
+pub mod plugin;
+
+pub use collider::{AutoSizeCollider, Collider, CollisionCheck};
+pub use plugin::{CollisionPlugin, CollisionSet};
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use super::collider::auto_size_colliders_system;
+
+// 콜라이더 갱신 시스템 묶음. 게임 쪽에서 실행 조건/순서를 정한다
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollisionSet;
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, auto_size_colliders_system.in_set(CollisionSet));
+    }
+}
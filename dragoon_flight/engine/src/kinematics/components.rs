@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Velocity2D {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Component)]
+pub struct Acceleration2D {
+    pub x: f32,
+    pub y: f32,
+}
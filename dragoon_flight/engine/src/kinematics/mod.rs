@@ -0,0 +1,5 @@
+pub mod components;
+pub mod plugin;
+
+pub use components::{Acceleration2D, Velocity2D};
+pub use plugin::{KinematicsPlugin, KinematicsSet};
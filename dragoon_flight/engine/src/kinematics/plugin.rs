@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use super::components::{Acceleration2D, Velocity2D};
+
+// 가속도 적용 시스템 묶음. 게임 쪽에서 실행 조건/순서를 정한다
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KinematicsSet;
+
+pub struct KinematicsPlugin;
+
+impl Plugin for KinematicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_acceleration_system.in_set(KinematicsSet));
+    }
+}
+
+// 속도에 가속도를 누적. 위치 반영은 엔티티 종류마다 다르므로 각 게임 시스템이 맡는다
+pub fn apply_acceleration_system(time: Res<Time>, mut q: Query<(&Acceleration2D, &mut Velocity2D)>) {
+    let dt = time.delta_secs();
+    for (acceleration, mut velocity) in &mut q {
+        velocity.x += acceleration.x * dt;
+        velocity.y += acceleration.y * dt;
+    }
+}
@@ -0,0 +1,11 @@
+// 게임과 무관한 공용 엔진 플러그인. 필요한 것만 feature로 골라 쓴다
+#[cfg(feature = "animation")]
+pub mod animation;
+#[cfg(feature = "collision")]
+pub mod collision;
+#[cfg(feature = "kinematics")]
+pub mod kinematics;
+#[cfg(feature = "platform")]
+pub mod platform;
+#[cfg(feature = "scene")]
+pub mod scene;
@@ -0,0 +1,56 @@
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowCloseRequested, WindowResolution};
+
+use super::service::{WindowDesc, WindowService};
+
+// winit 창을 쓰는 기본 구현. 창 엔티티를 직접 만들고, 닫기 요청도 여기서 처리한다
+#[derive(Default)]
+pub struct BevyWindowService {
+    window: Option<Entity>,
+    close_requests: EventCursor<WindowCloseRequested>,
+    close_requested: bool, // 코드에서 요청한 닫기
+}
+
+impl WindowService for BevyWindowService {
+    fn create_window(&mut self, world: &mut World, desc: &WindowDesc) {
+        if self.window.is_some() {
+            warn!("Window already created");
+            return;
+        }
+        let window = Window {
+            title: desc.title.clone(),
+            resolution: WindowResolution::new(desc.width as f32, desc.height as f32),
+            ..Default::default()
+        };
+        self.window = Some(world.spawn((window, PrimaryWindow)).id());
+        info!("Window '{}' {}x{}", desc.title, desc.width, desc.height);
+    }
+
+    fn destroy_window(&mut self, world: &mut World) {
+        if let Some(window) = self.window.take() {
+            world.despawn(window);
+        }
+    }
+
+    fn is_window_open(&self, world: &World) -> bool {
+        self.window.is_some_and(|window| world.get_entity(window).is_ok())
+    }
+
+    fn process_events(&mut self, world: &mut World) {
+        let events = world.resource::<Events<WindowCloseRequested>>();
+        let close_clicked = self
+            .close_requests
+            .read(events)
+            .any(|event| Some(event.window) == self.window);
+        let close = std::mem::take(&mut self.close_requested) || close_clicked;
+        if close {
+            info!("Window close requested");
+            self.destroy_window(world);
+        }
+    }
+
+    fn request_close(&mut self) {
+        self.close_requested = true;
+    }
+}
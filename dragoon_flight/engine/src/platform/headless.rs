@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::service::{WindowDesc, WindowService};
+
+// 실제 창 없이 열림/닫힘 상태만 흉내 내는 구현 (테스트, 서버용)
+#[derive(Default)]
+pub struct HeadlessWindowService {
+    desc: Option<WindowDesc>,
+    close_requested: bool,
+}
+
+impl WindowService for HeadlessWindowService {
+    fn create_window(&mut self, _world: &mut World, desc: &WindowDesc) {
+        info!("Headless window '{}' {}x{}", desc.title, desc.width, desc.height);
+        self.desc = Some(desc.clone());
+    }
+
+    fn destroy_window(&mut self, _world: &mut World) {
+        self.desc = None;
+    }
+
+    fn is_window_open(&self, _world: &World) -> bool {
+        self.desc.is_some()
+    }
+
+    fn process_events(&mut self, world: &mut World) {
+        if std::mem::take(&mut self.close_requested) {
+            self.destroy_window(world);
+        }
+    }
+
+    fn request_close(&mut self) {
+        self.close_requested = true;
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bevy_window;
+pub mod headless;
+pub mod plugin;
+pub mod service;
+
+pub use bevy_window::BevyWindowService;
+pub use headless::HeadlessWindowService;
+pub use plugin::{Platform, PlatformKind, PlatformPlugin};
+pub use service::{WindowDesc, WindowService};
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use bevy::app::{PluginGroupBuilder, ScheduleRunnerPlugin};
+use bevy::prelude::*;
+use bevy::render::{RenderPlugin, settings::WgpuSettings};
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+
+use super::bevy_window::BevyWindowService;
+use super::headless::HeadlessWindowService;
+use super::service::{WindowDesc, WindowService};
+
+// 헤드리스 모드의 프레임 간격
+const HEADLESS_FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+// 시작할 때 고르는 플랫폼
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlatformKind {
+    #[default]
+    Windowed,
+    Headless, // 창/렌더링 없이 고정 간격으로 업데이트만 돈다
+}
+
+impl PlatformKind {
+    // --headless가 있으면 헤드리스
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        if args.into_iter().any(|arg| arg == "--headless") {
+            Self::Headless
+        } else {
+            Self::Windowed
+        }
+    }
+
+    // 플랫폼에 맞게 조정한 DefaultPlugins. 창은 WindowService가 만들고 닫으므로 Bevy 기본 창/종료 처리는 끈다
+    pub fn default_plugins(self) -> PluginGroupBuilder {
+        let plugins = DefaultPlugins.set(WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            close_when_requested: false,
+        });
+        match self {
+            Self::Windowed => plugins,
+            Self::Headless => plugins
+                .disable::<WinitPlugin>()
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings { backends: None, ..Default::default() }.into(),
+                    ..Default::default()
+                })
+                .add(ScheduleRunnerPlugin::run_loop(HEADLESS_FRAME)),
+        }
+    }
+
+    fn window_service(self) -> Box<dyn WindowService> {
+        match self {
+            Self::Windowed => Box::new(BevyWindowService::default()),
+            Self::Headless => Box::new(HeadlessWindowService::default()),
+        }
+    }
+}
+
+// 선택된 플랫폼과 창 서비스
+#[derive(Resource)]
+pub struct Platform {
+    pub kind: PlatformKind,
+    window: Box<dyn WindowService>,
+}
+
+impl Platform {
+    pub fn window(&self) -> &dyn WindowService {
+        self.window.as_ref()
+    }
+
+    // 다음 프레임에 창을 닫고 앱을 종료
+    pub fn request_close(&mut self) {
+        self.window.request_close();
+    }
+}
+
+// legacy CoreEngine의 initialize/run/shutdown 수명 주기.
+// 시작 시 창을 만들고, 매 프레임 창 이벤트를 처리하다 창이 닫히면 앱을 종료하고, 종료 시 창을 정리한다
+pub struct PlatformPlugin {
+    pub kind: PlatformKind,
+    pub window: WindowDesc,
+}
+
+impl Plugin for PlatformPlugin {
+    fn build(&self, app: &mut App) {
+        let desc = self.window.clone();
+        app.insert_resource(Platform {
+            kind: self.kind,
+            window: self.kind.window_service(),
+        })
+        .add_systems(PreStartup, move |world: &mut World| {
+            world.resource_scope(|world, mut platform: Mut<Platform>| platform.window.create_window(world, &desc));
+        })
+        .add_systems(First, process_platform_events)
+        .add_systems(Last, shutdown_platform.run_if(on_event::<AppExit>));
+        info!("Platform: {:?}", self.kind);
+    }
+}
+
+fn process_platform_events(world: &mut World) {
+    let open = world.resource_scope(|world, mut platform: Mut<Platform>| {
+        platform.window.process_events(world);
+        platform.window.is_window_open(world)
+    });
+    if !open {
+        world.send_event(AppExit::Success);
+    }
+}
+
+fn shutdown_platform(world: &mut World) {
+    world.resource_scope(|world, mut platform: Mut<Platform>| {
+        if platform.window.is_window_open(world) {
+            platform.window.destroy_window(world);
+        }
+    });
+    info!("Platform shut down");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headless_close_request_exits_app() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            PlatformPlugin {
+                kind: PlatformKind::Headless,
+                window: WindowDesc {
+                    title: "test".to_string(),
+                    width: 320,
+                    height: 240,
+                },
+            },
+        ));
+        app.update();
+        let open = |app: &App| app.world().resource::<Platform>().window().is_window_open(app.world());
+        assert!(open(&app));
+        assert_eq!(app.should_exit(), None);
+
+        app.world_mut().resource_mut::<Platform>().request_close();
+        app.update();
+        assert!(!open(&app));
+        assert_eq!(app.should_exit(), Some(AppExit::Success));
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+// 만들 창의 설정
+#[derive(Debug, Clone)]
+pub struct WindowDesc {
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+// 창/플랫폼 서비스 (legacy IWindowService의 Rust 버전).
+// Bevy의 창은 월드 안의 엔티티라 각 메서드가 월드를 받는다
+pub trait WindowService: Send + Sync + 'static {
+    fn create_window(&mut self, world: &mut World, desc: &WindowDesc);
+
+    fn destroy_window(&mut self, world: &mut World);
+
+    fn is_window_open(&self, world: &World) -> bool;
+
+    // 매 프레임 창 이벤트 처리 (닫기 요청 등)
+    fn process_events(&mut self, world: &mut World);
+
+    // 다음 process_events에서 창을 닫음 (창의 X 버튼, 메뉴의 종료 등)
+    fn request_close(&mut self);
+}
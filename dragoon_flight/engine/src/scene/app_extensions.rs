@@ -0,0 +1,106 @@
+use super::scene_registry::SceneRegistry;
+use super::scene_scoped;
+use super::scene_stack;
+use super::scene_states::SceneStatus;
+use super::scene_traits::{IScene, SceneResources};
+use bevy::prelude::*;
+
+// 씬 Update 시스템의 순서. UI는 게임플레이 갱신 뒤에 돈다
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SceneSet {
+    Update,
+    Ui,
+}
+
+pub trait AppSceneExtensions {
+    fn add_scene<T: IScene + 'static>(&mut self, scene: T) -> &mut Self;
+
+    // 씬이 아닌 곳(플러그인의 시작 전환 등)에서 쓰는 씬 ID도 시작 시 검사 대상에 넣음
+    fn require_scene(&mut self, from: &'static str, scene: SceneStatus) -> &mut Self;
+
+    // 씬 밖의 시스템이 일으키는 전환을 선언 (예: 실행 옵션에 따라 시작 씬에서 바로 이동)
+    fn allow_transition(&mut self, from: SceneStatus, to: SceneStatus) -> &mut Self;
+}
+
+impl AppSceneExtensions for App {
+    fn add_scene<T: IScene + 'static>(&mut self, scene: T) -> &mut Self {
+        let state = scene.state();
+        let owner = std::any::type_name::<T>();
+        let mut registry = self.world_mut().get_resource_or_init::<SceneRegistry>();
+        registry.register(state, owner);
+        for target in scene.transitions() {
+            registry.reference(owner, target);
+            registry.declare(state, target);
+        }
+
+        let mut resources = SceneResources::default();
+        scene.resources(&mut resources);
+        let (insert_resources, remove_resources) = resources.into_systems();
+
+        // 스택에서 재개되는 경우엔 리소스/진입 시스템 대신 OnResume
+        self.add_systems(
+                OnEnter(state),
+                (insert_resources, scene.system_on_enter())
+                    .chain()
+                    .run_if(not(scene_stack::is_resuming(state))),
+            )
+            .add_systems(
+                Update,
+                scene
+                    .system_on_update()
+                    .in_set(SceneSet::Update)
+                    .run_if(in_state(state)),
+            );
+        // 씬 자체 정리 -> SceneScoped 엔티티 제거 -> 씬 리소스 제거. 다른 씬에 덮일 때는 OnPause
+        let despawn_scoped = scene_scoped::despawn_scoped(state);
+        let on_exit = match scene.system_on_exit() {
+            Some(on_exit) => (on_exit, despawn_scoped, remove_resources).chain(),
+            None => (despawn_scoped, remove_resources).chain(),
+        };
+        self.add_systems(OnExit(state), on_exit.run_if(not(scene_stack::is_covered(state))));
+
+        let (mut on_pause, mut on_resume) = (scene.system_on_pause(), scene.system_on_resume());
+        if !scene.render_while_paused() {
+            let hide = scene_stack::hide_scene(state).into_configs();
+            let show = scene_stack::show_scene(state).into_configs();
+            on_pause = Some(match on_pause {
+                Some(systems) => (systems, hide).chain(),
+                None => hide,
+            });
+            on_resume = Some(match on_resume {
+                Some(systems) => (show, systems).chain(),
+                None => show,
+            });
+        }
+        if let Some(systems) = on_pause {
+            self.add_systems(OnExit(state), systems.run_if(scene_stack::is_covered(state)));
+        }
+        if let Some(systems) = on_resume {
+            self.add_systems(OnEnter(state), systems.run_if(scene_stack::is_resuming(state)));
+        }
+        if let Some(systems) = scene.system_on_ui() {
+            self.add_systems(Update, systems.in_set(SceneSet::Ui).run_if(in_state(state)));
+        }
+        if let Some(systems) = scene.system_on_fixed_update() {
+            self.add_systems(FixedUpdate, systems.run_if(in_state(state)));
+        }
+        if let Some(systems) = scene.system_on_late_update() {
+            self.add_systems(PostUpdate, systems.run_if(in_state(state)));
+        }
+        self
+    }
+
+    fn require_scene(&mut self, from: &'static str, scene: SceneStatus) -> &mut Self {
+        self.world_mut().get_resource_or_init::<SceneRegistry>().reference(from, scene);
+        self
+    }
+
+    fn allow_transition(&mut self, from: SceneStatus, to: SceneStatus) -> &mut Self {
+        let mut registry = self.world_mut().get_resource_or_init::<SceneRegistry>();
+        registry.reference("allow_transition", from);
+        registry.reference("allow_transition", to);
+        registry.declare(from, to);
+        self
+    }
+}
+
@@ -0,0 +1,16 @@
+// 공개 모듈
+pub mod app_extensions;
+pub mod plugin;
+pub mod scene_registry;
+pub mod scene_scoped;
+pub mod scene_stack;
+pub mod scene_states;
+pub mod scene_traits;
+
+pub use app_extensions::{AppSceneExtensions, SceneSet};
+pub use plugin::SceneManagerPlugin;
+pub use scene_registry::SceneRegistry;
+pub use scene_scoped::{Persistent, SceneScoped};
+pub use scene_stack::SceneStack;
+pub use scene_states::SceneStatus;
+pub use scene_traits::{IScene, SceneResources};
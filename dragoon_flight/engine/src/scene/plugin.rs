@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use super::app_extensions::{AppSceneExtensions, SceneSet};
+use super::scene_registry::SceneRegistry;
+use super::scene_scoped;
+use super::scene_stack::{self, SceneStack};
+use super::scene_states::SceneStatus;
+
+// 씬 관리 기반 (스택, 자동 정리, 등록 검사). 씬 자체는 각 플러그인이 add_scene으로 등록한다
+pub struct SceneManagerPlugin {
+    pub initial: SceneStatus,
+}
+
+impl Plugin for SceneManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_state(self.initial)
+            .init_resource::<SceneRegistry>()
+            .init_resource::<SceneStack>()
+            .require_scene("initial state", self.initial)
+            .configure_sets(Update, SceneSet::Ui.after(SceneSet::Update))
+            .add_systems(PreUpdate, scene_stack::apply_scene_stack)
+            .add_systems(Last, scene_scoped::scope_new_entities);
+    }
+
+    // 모든 플러그인이 씬을 등록한 뒤에 검사
+    fn finish(&self, app: &mut App) {
+        let registry = app.world().resource::<SceneRegistry>();
+        if let Err(problems) = registry.validate() {
+            panic!("Invalid scene registry:\n{}", problems);
+        }
+        info!("Scenes registered: {}", registry.ids().collect::<Vec<_>>().join(", "));
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use super::scene_states::SceneStatus;
+
+struct SceneEntry {
+    owner: &'static str, // 등록한 IScene 타입 이름
+    duplicates: Vec<&'static str>,
+}
+
+// ID로 찾는 씬 목록. add_scene이 채우고, 시작 시 참조된 씬이 모두 있는지 검사한다.
+// 씬마다 선언한 전환(IScene::transitions)도 모아 두고, 실제 전환이 선언과 다르면 기록한다
+#[derive(Resource, Default)]
+pub struct SceneRegistry {
+    scenes: BTreeMap<&'static str, SceneEntry>,
+    references: Vec<(&'static str, SceneStatus)>, // (참조한 곳, 대상 씬)
+    declared: Vec<(SceneStatus, SceneStatus)>,    // (출발 씬, 도착 씬)
+    undeclared: Vec<(SceneStatus, SceneStatus)>,  // 실행 중 발견한 선언되지 않은 전환
+}
+
+impl SceneRegistry {
+    pub fn contains(&self, scene: SceneStatus) -> bool {
+        self.scenes.contains_key(scene.id())
+    }
+
+    // 대소문자 구분 없이 ID로 찾기 (콘솔 명령용)
+    pub fn find(&self, id: &str) -> Option<SceneStatus> {
+        self.scenes
+            .keys()
+            .find(|registered| registered.eq_ignore_ascii_case(id))
+            .map(|&registered| SceneStatus::new(registered))
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.scenes.keys().copied()
+    }
+
+    pub(super) fn register(&mut self, scene: SceneStatus, owner: &'static str) {
+        self.scenes
+            .entry(scene.id())
+            .and_modify(|entry| entry.duplicates.push(owner))
+            .or_insert(SceneEntry { owner, duplicates: Vec::new() });
+    }
+
+    pub(super) fn reference(&mut self, from: &'static str, scene: SceneStatus) {
+        self.references.push((from, scene));
+    }
+
+    pub(super) fn declare(&mut self, from: SceneStatus, to: SceneStatus) {
+        if !self.is_declared(from, to) {
+            self.declared.push((from, to));
+        }
+    }
+
+    pub fn is_declared(&self, from: SceneStatus, to: SceneStatus) -> bool {
+        self.declared.contains(&(from, to))
+    }
+
+    // 선언 없이 일어난 전환. 같은 전환은 한 번만 기록하고 에러로 알린다
+    pub(super) fn record_undeclared(&mut self, from: SceneStatus, to: SceneStatus) {
+        if self.undeclared.contains(&(from, to)) {
+            return;
+        }
+        error!(
+            "Scene '{}' switched to '{}' without declaring it in transitions()",
+            from.id(),
+            to.id()
+        );
+        self.undeclared.push((from, to));
+    }
+
+    pub fn undeclared(&self) -> &[(SceneStatus, SceneStatus)] {
+        &self.undeclared
+    }
+
+    // 중복 등록과 등록되지 않은 씬 참조를 한 번에 모아서 보고
+    pub(super) fn validate(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+        for (id, entry) in &self.scenes {
+            for duplicate in &entry.duplicates {
+                problems.push(format!("scene '{}' registered by both {} and {}", id, entry.owner, duplicate));
+            }
+        }
+        for (from, scene) in &self.references {
+            if !self.contains(*scene) {
+                problems.push(format!("{} refers to unregistered scene '{}'", from, scene.id()));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("\n"))
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::scene_states::SceneStatus;
+
+// 이 씬이 활성일 때 생성된 엔티티. 씬을 나갈 때 프레임워크가 제거한다
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SceneScoped(pub SceneStatus);
+
+// 씬이 바뀌어도 유지되는 엔티티 (전역 오버레이 등). SceneScoped가 붙지 않는다
+// Transform이 없는 엔티티(음악 등)는 원래 대상이 아니므로 붙일 필요 없음
+#[derive(Component, Default)]
+pub struct Persistent;
+
+// 이번 프레임에 생성된 최상위 엔티티에 현재 씬을 붙임. 자식은 부모와 함께 제거된다
+// Transform이 있는 엔티티(스프라이트, UI, 카메라, 파티클)만 대상이라 오디오/게임패드 엔티티는 건드리지 않는다
+#[allow(clippy::type_complexity)]
+pub fn scope_new_entities(
+    mut commands: Commands,
+    state: Res<State<SceneStatus>>,
+    spawned: Query<Entity, (Added<Transform>, Without<Parent>, Without<SceneScoped>, Without<Persistent>)>,
+) {
+    for entity in &spawned {
+        commands.entity(entity).try_insert(SceneScoped(*state.get()));
+    }
+}
+
+pub fn despawn_scoped(scene: SceneStatus) -> impl FnMut(Commands, Query<(Entity, &SceneScoped)>) {
+    move |mut commands: Commands, scoped: Query<(Entity, &SceneScoped)>| {
+        for (entity, SceneScoped(owner)) in &scoped {
+            if *owner == scene {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
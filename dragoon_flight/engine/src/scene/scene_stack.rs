@@ -0,0 +1,320 @@
+use bevy::prelude::*;
+
+use super::scene_registry::SceneRegistry;
+use super::scene_scoped::SceneScoped;
+use super::scene_states::SceneStatus;
+
+// 스택 조작 요청. 다음 프레임 상태 전환 직전에 적용된다
+#[derive(Debug, Clone, Copy)]
+enum StackOp {
+    Push(SceneStatus),
+    Pop,
+    Replace(SceneStatus),
+}
+
+// 씬 스택. 맨 위가 현재 State<SceneStatus>이고, 그 아래 씬은 일시정지 상태로 남는다
+// (엔티티/리소스 유지, Update 중단). NextState를 직접 set하면 스택이 그 씬 하나로 정리된다
+#[derive(Resource, Default)]
+pub struct SceneStack {
+    scenes: Vec<SceneStatus>, // 바닥부터
+    pending: Option<StackOp>,
+    resuming: Option<SceneStatus>,
+    unchecked: bool, // 다음 전환은 선언 검사를 건너뜀
+}
+
+impl SceneStack {
+    // 현재 씬 위에 새 씬을 올림. 현재 씬은 OnExit 대신 OnPause
+    pub fn push(&mut self, scene: SceneStatus) {
+        self.request(StackOp::Push(scene));
+    }
+
+    // 맨 위 씬을 내리고 아래 씬을 OnEnter 대신 OnResume으로 재개
+    pub fn pop(&mut self) {
+        self.request(StackOp::Pop);
+    }
+
+    // 맨 위 씬만 교체. 아래 씬들은 그대로 일시정지
+    pub fn replace(&mut self, scene: SceneStatus) {
+        self.request(StackOp::Replace(scene));
+    }
+
+    pub fn scenes(&self) -> &[SceneStatus] {
+        &self.scenes
+    }
+
+    // 다른 씬 아래 깔려 일시정지 중인지
+    pub fn is_covered(&self, scene: SceneStatus) -> bool {
+        self.scenes.split_last().is_some_and(|(_, below)| below.contains(&scene))
+    }
+
+    // 이번 전환이 일시정지된 씬의 재개인지
+    pub fn is_resuming(&self, scene: SceneStatus) -> bool {
+        self.resuming == Some(scene)
+    }
+
+    // 다음 전환 한 번은 선언된 전환인지 검사하지 않음 (디버그 콘솔처럼 어디로든 이동하는 곳에서 사용)
+    pub fn skip_transition_check(&mut self) {
+        self.unchecked = true;
+    }
+
+    fn request(&mut self, op: StackOp) {
+        if let Some(previous) = self.pending.replace(op) {
+            warn!("Scene stack op {:?} overridden by {:?}", previous, op);
+        }
+    }
+}
+
+// 스택 요청(또는 NextState 직접 설정)을 반영해 NextState를 맞춘다.
+// 맨 위 씬의 퇴장/진입은 일반 상태 전환이 처리하고, 여기서는 스택에서 빠지는
+// 일시정지 씬들의 OnExit만 직접 실행한다
+pub fn apply_scene_stack(world: &mut World) {
+    let current = *world.resource::<State<SceneStatus>>().get();
+    let requested = match world.resource::<NextState<SceneStatus>>() {
+        NextState::Pending(next) => Some(*next),
+        NextState::Unchanged => None,
+    };
+
+    let (target, unchecked) = {
+        let mut stack = world.resource_mut::<SceneStack>();
+        let target = match stack.pending {
+            Some(StackOp::Push(scene) | StackOp::Replace(scene)) => Some(scene),
+            Some(StackOp::Pop) => None,
+            None => requested,
+        };
+        let unchecked = if stack.pending.is_some() || requested.is_some() {
+            std::mem::take(&mut stack.unchecked)
+        } else {
+            stack.unchecked
+        };
+        (target, unchecked)
+    };
+
+    // 새로 들어가는 씬이면 현재 씬의 transitions()에 있어야 함. 스택 아래로 돌아가는 건 항상 허용
+    let returning = target.is_some_and(|scene| scene == current || world.resource::<SceneStack>().scenes.contains(&scene));
+    if let Some(scene) = target
+        && !unchecked
+        && !returning
+        && world.resource::<SceneRegistry>().contains(scene)
+        && !world.resource::<SceneRegistry>().is_declared(current, scene)
+    {
+        world.resource_mut::<SceneRegistry>().record_undeclared(current, scene);
+    }
+
+    // 등록되지 않은 씬으로는 전환하지 않음 (OnEnter가 없어 빈 화면이 된다)
+    let unknown = target.filter(|&scene| !world.resource::<SceneRegistry>().contains(scene));
+    if let Some(scene) = unknown {
+        error!("Transition to unregistered scene '{}' ignored", scene.id());
+        world.resource_mut::<SceneStack>().pending = None;
+        world.resource_mut::<NextState<SceneStatus>>().reset();
+        return;
+    }
+
+    let mut stack = world.resource_mut::<SceneStack>();
+    if stack.scenes.is_empty() {
+        stack.scenes.push(current);
+    }
+    let Some(op) = stack.pending.take() else {
+        let Some(next) = requested else {
+            return;
+        };
+        // 스택에 있던 씬으로 가면 그 위만 걷어내고, 아니면 전부 교체
+        let mut scenes = stack.scenes.clone();
+        match scenes.iter().position(|&scene| scene == next) {
+            Some(index) if index + 1 < scenes.len() => scenes.truncate(index + 1),
+            Some(_) => {}
+            None => scenes = vec![next],
+        }
+        rebuild_stack(world, current, scenes);
+        return;
+    };
+
+    if let Some(next) = requested {
+        warn!("NextState {:?} ignored in favor of scene stack op {:?}", next, op);
+    }
+    let mut scenes = stack.scenes.clone();
+    match op {
+        StackOp::Push(scene) if scenes.contains(&scene) => {
+            warn!("Scene {:?} is already on the stack {:?}", scene, scenes);
+            world.resource_mut::<NextState<SceneStatus>>().reset();
+            return;
+        }
+        StackOp::Push(scene) => scenes.push(scene),
+        StackOp::Pop if scenes.len() < 2 => {
+            warn!("Cannot pop the last scene {:?}", current);
+            world.resource_mut::<NextState<SceneStatus>>().reset();
+            return;
+        }
+        StackOp::Pop => {
+            scenes.pop();
+        }
+        StackOp::Replace(scene) => {
+            scenes.pop();
+            scenes.retain(|&below| below != scene);
+            scenes.push(scene);
+        }
+    }
+    rebuild_stack(world, current, scenes);
+    let top = *world.resource::<SceneStack>().scenes.last().expect("scene stack is never empty");
+    world.resource_mut::<NextState<SceneStatus>>().set(top);
+}
+
+fn rebuild_stack(world: &mut World, current: SceneStatus, scenes: Vec<SceneStatus>) {
+    let mut stack = world.resource_mut::<SceneStack>();
+    let dropped: Vec<SceneStatus> = stack
+        .scenes
+        .iter()
+        .copied()
+        .filter(|scene| *scene != current && !scenes.contains(scene))
+        .collect();
+    let top = *scenes.last().expect("scene stack is never empty");
+    // 아래에 깔려 있던 씬이 다시 맨 위로 오면 재개
+    stack.resuming = (top != current && stack.is_covered(top)).then_some(top);
+    stack.scenes = scenes;
+    debug!("Scene stack -> {:?}", stack.scenes);
+
+    for scene in dropped {
+        let _ = world.try_run_schedule(OnExit(scene));
+    }
+}
+
+pub fn is_covered(scene: SceneStatus) -> impl Fn(Res<SceneStack>) -> bool + Clone {
+    move |stack: Res<SceneStack>| stack.is_covered(scene)
+}
+
+pub fn is_resuming(scene: SceneStatus) -> impl Fn(Res<SceneStack>) -> bool + Clone {
+    move |stack: Res<SceneStack>| stack.is_resuming(scene)
+}
+
+// 덮여 있는 동안 숨긴 엔티티의 원래 표시 상태
+#[derive(Component)]
+pub struct CoveredView {
+    visibility: Option<Visibility>,
+    camera_active: Option<bool>,
+}
+
+// 덮인 씬의 엔티티를 숨기고 카메라를 끔 (render_while_paused가 false인 씬)
+#[allow(clippy::type_complexity)]
+pub fn hide_scene(
+    scene: SceneStatus,
+) -> impl FnMut(Commands, Query<(Entity, &SceneScoped, Option<&mut Visibility>, Option<&mut Camera>)>) {
+    move |mut commands: Commands,
+          mut scoped: Query<(Entity, &SceneScoped, Option<&mut Visibility>, Option<&mut Camera>)>| {
+        for (entity, owner, visibility, camera) in &mut scoped {
+            if owner.0 != scene {
+                continue;
+            }
+            let view = CoveredView {
+                visibility: visibility.map(|mut v| std::mem::replace(&mut *v, Visibility::Hidden)),
+                camera_active: camera.map(|mut c| std::mem::replace(&mut c.is_active, false)),
+            };
+            commands.entity(entity).insert(view);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn show_scene(
+    scene: SceneStatus,
+) -> impl FnMut(Commands, Query<(Entity, &SceneScoped, &CoveredView, Option<&mut Visibility>, Option<&mut Camera>)>) {
+    move |mut commands: Commands,
+          mut scoped: Query<(Entity, &SceneScoped, &CoveredView, Option<&mut Visibility>, Option<&mut Camera>)>| {
+        for (entity, owner, view, visibility, camera) in &mut scoped {
+            if owner.0 != scene {
+                continue;
+            }
+            if let (Some(mut visibility), Some(previous)) = (visibility, view.visibility) {
+                *visibility = previous;
+            }
+            if let (Some(mut camera), Some(previous)) = (camera, view.camera_active) {
+                camera.is_active = previous;
+            }
+            commands.entity(entity).remove::<CoveredView>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::schedule::SystemConfigs;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::scene::{AppSceneExtensions, IScene, SceneManagerPlugin};
+
+    const A: SceneStatus = SceneStatus::new("A");
+    const B: SceneStatus = SceneStatus::new("B");
+    const C: SceneStatus = SceneStatus::new("C");
+
+    struct TestScene(SceneStatus, Vec<SceneStatus>);
+
+    impl IScene for TestScene {
+        fn state(&self) -> SceneStatus {
+            self.0
+        }
+
+        fn system_on_enter(&self) -> SystemConfigs {
+            (|| {}).into_configs()
+        }
+
+        fn system_on_update(&self) -> SystemConfigs {
+            (|| {}).into_configs()
+        }
+
+        fn transitions(&self) -> Vec<SceneStatus> {
+            self.1.clone()
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, SceneManagerPlugin { initial: A }))
+            .add_scene(TestScene(A, vec![B]))
+            .add_scene(TestScene(B, Vec::new()))
+            .add_scene(TestScene(C, Vec::new()));
+        app.finish();
+        app.cleanup();
+        app.update();
+        app
+    }
+
+    fn current(app: &App) -> SceneStatus {
+        *app.world().resource::<State<SceneStatus>>().get()
+    }
+
+    fn undeclared(app: &App) -> Vec<(SceneStatus, SceneStatus)> {
+        app.world().resource::<SceneRegistry>().undeclared().to_vec()
+    }
+
+    #[test]
+    fn declared_transitions_and_returns_pass() {
+        let mut app = app();
+        app.world_mut().resource_mut::<NextState<SceneStatus>>().set(B);
+        app.update();
+        assert_eq!(current(&app), B);
+
+        // B에서 A로 바로 가는 건 선언되지 않았지만, 스택 아래로 돌아가는 pop은 항상 허용
+        app.world_mut().resource_mut::<SceneStack>().skip_transition_check();
+        app.world_mut().resource_mut::<SceneStack>().push(C);
+        app.update();
+        assert_eq!(current(&app), C);
+        app.world_mut().resource_mut::<SceneStack>().pop();
+        app.update();
+        assert_eq!(current(&app), B);
+        assert!(undeclared(&app).is_empty());
+    }
+
+    #[test]
+    fn undeclared_transition_is_recorded_once() {
+        let mut app = app();
+        app.world_mut().resource_mut::<NextState<SceneStatus>>().set(C);
+        app.update();
+        assert_eq!(current(&app), C);
+        assert_eq!(undeclared(&app), vec![(A, C)]);
+
+        app.world_mut().resource_mut::<NextState<SceneStatus>>().set(A);
+        app.update();
+        app.world_mut().resource_mut::<NextState<SceneStatus>>().set(C);
+        app.update();
+        assert_eq!(undeclared(&app), vec![(A, C), (C, A)]);
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+use bevy::prelude::States;
+
+// 씬 ID. 게임(또는 플러그인)이 SceneStatus::new로 상수를 만들어 쓴다
+#[derive(Clone, Copy, Eq, PartialEq, Hash, States)]
+pub struct SceneStatus(&'static str);
+
+impl SceneStatus {
+    pub const fn new(id: &'static str) -> Self {
+        Self(id)
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.0
+    }
+}
+
+// 로그에는 ID만
+impl fmt::Debug for SceneStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use super::scene_states::SceneStatus;
+use bevy::ecs::schedule::SystemConfigs;
+use bevy::prelude::*;
+
+pub trait IScene: Send + Sync + 'static {
+    fn state(&self) -> SceneStatus;
+    fn system_on_enter(&self) -> SystemConfigs;
+    fn system_on_update(&self) -> SystemConfigs;
+
+    // 이 씬에서 전환(set/push/replace)할 수 있는 씬. 시작할 때 모두 등록됐는지 검사한다
+    fn transitions(&self) -> Vec<SceneStatus> {
+        Vec::new()
+    }
+
+    // 씬 고유의 정리. 진입 후 생성된 엔티티는 SceneScoped로 자동 제거되므로 보통 필요 없다
+    fn system_on_exit(&self) -> Option<SystemConfigs> {
+        None
+    }
+
+    // 스택에서 다른 씬이 위에 올라왔을 때 / 다시 맨 위가 됐을 때. 이때는 OnExit/OnEnter가 돌지 않는다
+    fn system_on_pause(&self) -> Option<SystemConfigs> {
+        None
+    }
+
+    fn system_on_resume(&self) -> Option<SystemConfigs> {
+        None
+    }
+
+    // 덮여 있는 동안에도 계속 그릴지. false면 덮인 동안 엔티티를 숨기고 카메라를 끈다
+    fn render_while_paused(&self) -> bool {
+        false
+    }
+
+    // 고정 틱(FixedUpdate)에서 돌 시스템
+    fn system_on_fixed_update(&self) -> Option<SystemConfigs> {
+        None
+    }
+
+    // 모든 Update가 끝난 뒤(PostUpdate)에 돌 시스템
+    fn system_on_late_update(&self) -> Option<SystemConfigs> {
+        None
+    }
+
+    // Update 안에서 system_on_update 이후에 돌 UI 갱신 시스템
+    fn system_on_ui(&self) -> Option<SystemConfigs> {
+        None
+    }
+
+    // 씬이 소유하는 리소스. 진입 시 on_enter보다 먼저 넣고, 퇴장 시 on_exit과 엔티티 정리 뒤에 제거한다
+    fn resources(&self, _resources: &mut SceneResources) {}
+}
+
+type ResourceOp = Box<dyn Fn(&mut World) + Send + Sync>;
+
+// 씬 소유 리소스 선언
+#[derive(Default)]
+pub struct SceneResources {
+    inserts: Vec<ResourceOp>,
+    removes: Vec<ResourceOp>,
+}
+
+impl SceneResources {
+    // 진입할 때마다 FromWorld(또는 Default)로 새로 만든다
+    pub fn add<R: Resource + FromWorld>(&mut self) -> &mut Self {
+        self.add_with(|world: &mut World| R::from_world(world))
+    }
+
+    pub fn add_with<R: Resource>(&mut self, make: impl Fn(&mut World) -> R + Send + Sync + 'static) -> &mut Self {
+        self.inserts.push(Box::new(move |world| {
+            let resource = make(world);
+            world.insert_resource(resource);
+        }));
+        self.removes.push(Box::new(|world| {
+            world.remove_resource::<R>();
+        }));
+        self
+    }
+
+    // 진입/퇴장 시 실행할 배타 시스템 쌍
+    pub(super) fn into_systems(self) -> (impl FnMut(&mut World), impl FnMut(&mut World)) {
+        let inserts = Arc::new(self.inserts);
+        let removes = Arc::new(self.removes);
+        (
+            move |world: &mut World| inserts.iter().for_each(|insert| insert(world)),
+            move |world: &mut World| removes.iter().for_each(|remove| remove(world)),
+        )
+    }
+}
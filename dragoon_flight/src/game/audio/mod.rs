@@ -0,0 +1,3 @@
+pub mod music;
+pub mod plugin;
+pub mod sfx;
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::game::net::scene::NET_PLAY;
+use crate::game::scene_manager::scene_ids;
+use dragoon_engine::scene::{SceneStack, SceneStatus};
+use crate::game::settings::Settings;
+
+// 크로스페이드에 걸리는 시간(초)
+const CROSSFADE_SECS: f32 = 1.2;
+
+// 재생할 배경음악 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MusicCue {
+    Menu,
+    InGame,
+    Boss,
+    GameOver,
+}
+
+impl MusicCue {
+    // 오버레이(일시정지 등)나 음악을 정하지 않은 씬은 지금 곡을 그대로 둔다
+    pub fn for_scene(scene: SceneStatus) -> Option<Self> {
+        match scene {
+            scene_ids::MAIN
+            | scene_ids::STAGE_SELECT
+            | scene_ids::PRACTICE_SELECT
+            | scene_ids::STAGE_CLEAR => Some(MusicCue::Menu),
+            scene_ids::IN_GAME | NET_PLAY => Some(MusicCue::InGame),
+            scene_ids::GAME_OVER => Some(MusicCue::GameOver),
+            _ => None,
+        }
+    }
+}
+
+// 큐별 음악 에셋 경로
+#[derive(Resource)]
+pub struct MusicLibrary {
+    pub tracks: HashMap<MusicCue, String>,
+}
+
+impl Default for MusicLibrary {
+    fn default() -> Self {
+        let tracks = [
+            (MusicCue::Menu, "audio/music/menu.wav"),
+            (MusicCue::InGame, "audio/music/ingame.wav"),
+            (MusicCue::Boss, "audio/music/boss.wav"),
+            (MusicCue::GameOver, "audio/music/game_over.wav"),
+        ]
+        .into_iter()
+        .map(|(cue, path)| (cue, path.to_string()))
+        .collect();
+        Self { tracks }
+    }
+}
+
+// 현재 재생 중인 큐와 다음에 재생할 큐
+#[derive(Resource, Default)]
+pub struct MusicState {
+    requested: Option<MusicCue>,
+    playing: Option<MusicCue>,
+}
+
+impl MusicState {
+    // 다른 시스템(보스 등장 등)에서 음악 전환을 요청할 때 사용
+    pub fn request(&mut self, cue: MusicCue) {
+        self.requested = Some(cue);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FadeDirection {
+    In,
+    Out,
+}
+
+#[derive(Component)]
+pub struct MusicTrack {
+    level: f32, // 페이드 진행도 (0.0 ~ 1.0), 버스 볼륨과 곱해진다
+    direction: FadeDirection,
+}
+
+// 씬이 바뀌면 해당 씬의 기본 음악을 요청.
+// 씬의 OnEnter에서 이미 다른 곡을 요청했다면(스테이지 음악 등) 그 요청을 우선한다.
+// 스택에서 재개된 씬은 덮이기 전 곡(보스 음악 등)을 이어서 튼다
+pub fn request_scene_music(state: Res<State<SceneStatus>>, stack: Res<SceneStack>, mut music: ResMut<MusicState>) {
+    if !state.is_changed() || music.requested.is_some() || stack.is_resuming(*state.get()) {
+        return;
+    }
+    if let Some(cue) = MusicCue::for_scene(*state.get()) {
+        music.request(cue);
+    }
+}
+
+// 요청된 큐가 현재 곡과 다르면 기존 곡은 페이드아웃, 새 곡은 페이드인 시작
+pub fn start_requested_music(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    library: Res<MusicLibrary>,
+    mut music: ResMut<MusicState>,
+    mut tracks: Query<&mut MusicTrack>,
+) {
+    let Some(cue) = music.requested.take() else {
+        return;
+    };
+    if music.playing == Some(cue) {
+        return;
+    }
+    music.playing = Some(cue);
+
+    for mut track in &mut tracks {
+        track.direction = FadeDirection::Out;
+    }
+
+    let Some(path) = library.tracks.get(&cue) else {
+        warn!("No music track registered for {:?}", cue);
+        return;
+    };
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(path.as_str())),
+        PlaybackSettings::LOOP.with_volume(Volume::ZERO),
+        MusicTrack {
+            level: 0.0,
+            direction: FadeDirection::In,
+        },
+    ));
+    info!("Music -> {:?}", cue);
+}
+
+// 페이드 진행 및 버스 볼륨 반영. 페이드아웃이 끝난 곡은 제거한다.
+pub fn crossfade_music(
+    time: Res<Time<Real>>,
+    settings: Res<Settings>,
+    mut commands: Commands,
+    mut tracks: Query<(Entity, &mut MusicTrack, Option<&AudioSink>)>,
+) {
+    let step = time.delta_secs() / CROSSFADE_SECS;
+    let gain = settings.audio.music_gain();
+    for (entity, mut track, sink) in &mut tracks {
+        track.level = match track.direction {
+            FadeDirection::In => (track.level + step).min(1.0),
+            FadeDirection::Out => (track.level - step).max(0.0),
+        };
+        if track.direction == FadeDirection::Out && track.level <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        if let Some(sink) = sink {
+            sink.set_volume(gain * track.level);
+        }
+    }
+}
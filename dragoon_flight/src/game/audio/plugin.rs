@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use super::music::{self, MusicLibrary, MusicState};
+use super::sfx::{self, SfxLibrary};
+use crate::game::settings::Settings;
+
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Settings>()
+            .init_resource::<MusicLibrary>()
+            .init_resource::<MusicState>()
+            .init_resource::<SfxLibrary>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        music::request_scene_music,
+                        music::start_requested_music,
+                        music::crossfade_music,
+                    )
+                        .chain(),
+                    sfx::play_gameplay_sfx,
+                ),
+            );
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::game::events::GameplayEvent;
+use crate::game::settings::Settings;
+
+// 같은 효과음이 동시에 재생될 수 있는 최대 개수 (연사 시 클리핑 방지)
+const MAX_CONCURRENT_PER_SFX: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sfx {
+    Fire,
+    Hit,
+    EnemyDeath,
+    PlayerHurt,
+}
+
+impl Sfx {
+    fn from_event(event: &GameplayEvent) -> Self {
+        match event {
+            GameplayEvent::BulletFired { .. } => Sfx::Fire,
+            GameplayEvent::EnemyHit { .. } | GameplayEvent::EnemyCleared { .. } => Sfx::Hit,
+            GameplayEvent::EnemyKilled { .. } | GameplayEvent::PlayerDied { .. } => Sfx::EnemyDeath,
+            GameplayEvent::PlayerHurt { .. } => Sfx::PlayerHurt,
+        }
+    }
+
+    // 효과음별 기본 음량 (버스 볼륨과 곱해진다)
+    fn base_volume(&self) -> f32 {
+        match self {
+            Sfx::Fire => 0.35,
+            Sfx::Hit => 0.6,
+            Sfx::EnemyDeath => 0.9,
+            Sfx::PlayerHurt => 1.0,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct SfxLibrary {
+    handles: HashMap<Sfx, Handle<AudioSource>>,
+}
+
+impl FromWorld for SfxLibrary {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        let handles = [
+            (Sfx::Fire, "audio/sfx/fire.wav"),
+            (Sfx::Hit, "audio/sfx/hit.wav"),
+            (Sfx::EnemyDeath, "audio/sfx/enemy_death.wav"),
+            (Sfx::PlayerHurt, "audio/sfx/player_hurt.wav"),
+        ]
+        .into_iter()
+        .map(|(sfx, path)| (sfx, asset_server.load(path)))
+        .collect();
+        Self { handles }
+    }
+}
+
+// 재생 중인 효과음 인스턴스 (재생이 끝나면 PlaybackSettings::DESPAWN으로 제거됨)
+#[derive(Component)]
+pub struct SfxInstance(Sfx);
+
+pub fn play_gameplay_sfx(
+    mut commands: Commands,
+    mut events: EventReader<GameplayEvent>,
+    library: Res<SfxLibrary>,
+    settings: Res<Settings>,
+    playing: Query<&SfxInstance>,
+) {
+    if events.is_empty() {
+        return;
+    }
+
+    let mut active: HashMap<Sfx, usize> = HashMap::new();
+    for instance in &playing {
+        *active.entry(instance.0).or_default() += 1;
+    }
+
+    let gain = settings.audio.sfx_gain();
+    for event in events.read() {
+        let sfx = Sfx::from_event(event);
+        let count = active.entry(sfx).or_default();
+        if *count >= MAX_CONCURRENT_PER_SFX {
+            continue;
+        }
+        let Some(handle) = library.handles.get(&sfx) else {
+            continue;
+        };
+        *count += 1;
+        commands.spawn((
+            AudioPlayer::new(handle.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::new(gain * sfx.base_volume())),
+            SfxInstance(sfx),
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use crate::game::playfield::Playfield;
+
+// 레이어 하나의 설정. speed는 진행 속도(ScrollPacing)에 곱해지는 배율
+#[derive(Debug, Clone)]
+pub struct ParallaxLayerConfig {
+    pub image: String,
+    pub speed: f32,
+    pub z: f32,
+    pub tint: Color,
+}
+
+// 스테이지별 배경 구성. 앞쪽 레이어일수록 speed가 크다
+#[derive(Resource, Debug, Clone)]
+pub struct ParallaxConfig {
+    pub layers: Vec<ParallaxLayerConfig>,
+}
+
+impl Default for ParallaxConfig {
+    fn default() -> Self {
+        let layer = |image: &str, speed: f32, z: f32| ParallaxLayerConfig {
+            image: image.to_string(),
+            speed,
+            z,
+            tint: Color::WHITE,
+        };
+        Self {
+            layers: vec![
+                layer("background/far.png", 0.05, -30.0),
+                layer("background/mid.png", 0.2, -20.0),
+                layer("background/near.png", 0.5, -10.0),
+            ],
+        }
+    }
+}
+
+// 현재 스테이지의 진행 속도(px/s). 배경 스크롤의 기준값
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ScrollPacing {
+    pub speed: f32,
+}
+
+impl Default for ScrollPacing {
+    fn default() -> Self {
+        Self { speed: 240.0 }
+    }
+}
+
+// 한 레이어를 구성하는 타일. 플레이필드 너비만큼의 타일 두 장을 이어 붙여 순환시킨다
+#[derive(Component)]
+pub struct ParallaxTile {
+    speed: f32,
+}
+
+const TILES_PER_LAYER: usize = 2;
+
+pub fn spawn_parallax_layers(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    config: &ParallaxConfig,
+    size: Vec2,
+) {
+    for layer in &config.layers {
+        let image: Handle<Image> = asset_server.load(layer.image.as_str());
+        for index in 0..TILES_PER_LAYER {
+            commands.spawn((
+                Sprite {
+                    image: image.clone(),
+                    color: layer.tint,
+                    custom_size: Some(size),
+                    ..Default::default()
+                },
+                Transform::from_xyz(index as f32 * size.x, 0.0, layer.z),
+                ParallaxTile { speed: layer.speed },
+            ));
+        }
+    }
+}
+
+// 레이어별 속도로 스크롤하고, 플레이필드 왼쪽으로 완전히 벗어난 타일은 오른쪽 끝으로 보냄
+pub fn scroll_parallax_system(
+    time: Res<Time>,
+    pacing: Res<ScrollPacing>,
+    playfield: Res<Playfield>,
+    mut tiles: Query<(&ParallaxTile, &mut Transform)>,
+) {
+    let width = playfield.size.x;
+    let span = width * TILES_PER_LAYER as f32;
+
+    for (tile, mut tf) in &mut tiles {
+        tf.translation.x -= pacing.speed * tile.speed * time.delta_secs();
+        if tf.translation.x <= -width {
+            tf.translation.x += span;
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// build.rs가 심은 빌드 정보. 메인 화면, 세이브 파일, 크래시 로그에 남긴다
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    pub version: String,
+    pub git: String,
+    pub profile: String,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git: env!("DRAGOON_GIT_DESCRIBE").to_string(),
+            profile: env!("DRAGOON_BUILD_PROFILE").to_string(),
+        }
+    }
+
+    // 세이브 포맷은 패키지 버전 단위로 바뀌므로 버전만 비교한다
+    pub fn is_compatible(&self, other: &BuildInfo) -> bool {
+        self.version == other.version
+    }
+}
+
+impl Default for BuildInfo {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{} ({}, {})", self.version, self.git, self.profile)
+    }
+}
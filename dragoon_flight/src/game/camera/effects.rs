@@ -0,0 +1,218 @@
+use bevy::prelude::*;
+
+use crate::game::events::GameplayEvent;
+use crate::game::playfield::PlayfieldCamera;
+use dragoon_engine::scene::Persistent;
+use crate::game::settings::Settings;
+
+// 트라우마 기반 화면 흔들림. 흔들림 세기는 trauma^2에 비례
+#[derive(Component)]
+pub struct CameraShake {
+    pub trauma: f32,     // 0.0 ~ 1.0
+    pub decay: f32,      // 초당 감소량
+    pub max_offset: f32, // 최대 이동량(px)
+    pub max_angle: f32,  // 최대 회전량(라디안)
+    base: Vec3,   // 흔들리기 시작할 때의 카메라 위치. 흔들림이 끝나면 여기로 돌아온다
+    shaking: bool,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.5,
+            max_offset: 18.0,
+            max_angle: 0.04,
+            base: Vec3::ZERO,
+            shaking: false,
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+}
+
+// 처치 순간 시간을 잠깐 늦추는 히트스톱
+#[derive(Resource, Default)]
+pub struct HitStop {
+    remaining: f32,
+    restore_speed: Option<f32>,
+}
+
+impl HitStop {
+    pub fn trigger(&mut self, duration: f32) {
+        self.remaining = self.remaining.max(duration);
+    }
+}
+
+// 플레이필드를 덮는 플래시 오버레이. 플레이필드 카메라가 있으면 그 뷰포트(레터박스 안쪽)에 그린다
+#[derive(Component)]
+pub struct ScreenFlash {
+    color: Color,
+    alpha: f32,
+    fade_per_sec: f32,
+}
+
+impl ScreenFlash {
+    pub fn flash(&mut self, color: Color, alpha: f32, duration: f32) {
+        self.color = color;
+        self.alpha = self.alpha.max(alpha);
+        self.fade_per_sec = self.alpha / duration.max(0.01);
+    }
+}
+
+const HIT_STOP_SPEED: f32 = 0.05;
+
+pub fn spawn_flash_overlay(mut commands: Commands) {
+    commands.spawn((
+        Persistent,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..Default::default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(i32::MAX - 1),
+        PickingBehavior::IGNORE,
+        ScreenFlash {
+            color: Color::NONE,
+            alpha: 0.0,
+            fade_per_sec: 0.0,
+        },
+    ));
+}
+
+// 오버레이를 플레이필드 카메라에 붙인다. 카메라가 사라지면 기본 UI 카메라로 돌아감
+pub fn target_flash_overlay(
+    mut commands: Commands,
+    added: Query<Entity, Added<PlayfieldCamera>>,
+    mut removed: RemovedComponents<PlayfieldCamera>,
+    overlays: Query<(Entity, Option<&TargetCamera>), With<ScreenFlash>>,
+) {
+    for camera in removed.read() {
+        for (overlay, target) in &overlays {
+            if target.is_some_and(|target| target.entity() == camera) {
+                commands.entity(overlay).remove::<TargetCamera>();
+            }
+        }
+    }
+    if let Some(camera) = added.iter().last() {
+        for (overlay, _) in &overlays {
+            commands.entity(overlay).insert(TargetCamera(camera));
+        }
+    }
+}
+
+// 게임플레이 이벤트를 카메라 효과로 변환
+pub fn apply_gameplay_camera_effects(
+    settings: Res<Settings>,
+    mut events: EventReader<GameplayEvent>,
+    mut shakes: Query<&mut CameraShake>,
+    mut flashes: Query<&mut ScreenFlash>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    let cfg = settings.camera;
+    for event in events.read() {
+        let (trauma, flash, stop) = match event {
+            GameplayEvent::PlayerHurt { .. } => {
+                (0.6, Some((Color::srgb(1.0, 0.1, 0.1), 0.35, 0.3)), None)
+            }
+            GameplayEvent::PlayerDied { .. } => {
+                (0.9, Some((Color::srgb(1.0, 0.3, 0.1), 0.5, 0.6)), Some(0.15))
+            }
+            GameplayEvent::EnemyKilled { .. } => {
+                (0.25, Some((Color::WHITE, 0.08, 0.1)), Some(0.06))
+            }
+            _ => (0.0, None, None),
+        };
+
+        if cfg.screen_shake && trauma > 0.0 {
+            for mut shake in &mut shakes {
+                shake.add_trauma(trauma * cfg.shake_intensity);
+            }
+        }
+        if cfg.flashes
+            && let Some((color, alpha, duration)) = flash
+        {
+            for mut overlay in &mut flashes {
+                overlay.flash(color, alpha * cfg.flash_intensity, duration);
+            }
+        }
+        if cfg.hit_stop
+            && let Some(duration) = stop
+        {
+            hit_stop.trigger(duration);
+        }
+    }
+}
+
+// 흔들림 적용. 히트스톱 중에도 흔들리도록 실제 시간 기준
+pub fn update_camera_shake(
+    time: Res<Time<Real>>,
+    mut cameras: Query<(&mut CameraShake, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    let t = time.elapsed_secs();
+    for (mut shake, mut tf) in &mut cameras {
+        if shake.trauma <= 0.0 {
+            continue;
+        }
+        if !shake.shaking {
+            shake.base = tf.translation;
+            shake.shaking = true;
+        }
+        shake.trauma = (shake.trauma - shake.decay * dt).max(0.0);
+        let power = shake.trauma * shake.trauma;
+
+        // 주파수가 다른 사인파를 섞은 값싼 노이즈
+        let nx = (t * 41.0).sin() * 0.6 + (t * 97.0 + 1.3).sin() * 0.4;
+        let ny = (t * 53.0 + 2.1).sin() * 0.6 + (t * 89.0 + 0.7).sin() * 0.4;
+        let nr = (t * 61.0 + 4.2).sin();
+
+        tf.translation = shake.base + Vec3::new(nx, ny, 0.0) * shake.max_offset * power;
+        tf.rotation = Quat::from_rotation_z(nr * shake.max_angle * power);
+
+        if shake.trauma <= 0.0 {
+            tf.translation = shake.base;
+            tf.rotation = Quat::IDENTITY;
+            shake.shaking = false;
+        }
+    }
+}
+
+pub fn update_hit_stop(
+    real: Res<Time<Real>>,
+    mut virt: ResMut<Time<Virtual>>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    if hit_stop.remaining <= 0.0 {
+        return;
+    }
+    if hit_stop.restore_speed.is_none() {
+        hit_stop.restore_speed = Some(virt.relative_speed());
+        virt.set_relative_speed(HIT_STOP_SPEED);
+    }
+    hit_stop.remaining -= real.delta_secs();
+    if hit_stop.remaining <= 0.0
+        && let Some(speed) = hit_stop.restore_speed.take()
+    {
+        virt.set_relative_speed(speed);
+    }
+}
+
+pub fn update_screen_flash(
+    time: Res<Time<Real>>,
+    mut overlays: Query<(&mut ScreenFlash, &mut BackgroundColor)>,
+) {
+    for (mut flash, mut bg) in &mut overlays {
+        if flash.alpha <= 0.0 {
+            continue;
+        }
+        flash.alpha = (flash.alpha - flash.fade_per_sec * time.delta_secs()).max(0.0);
+        bg.0 = flash.color.with_alpha(flash.alpha);
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::effects::{self, HitStop};
+use crate::game::settings::Settings;
+
+pub struct CameraEffectsPlugin;
+
+impl Plugin for CameraEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Settings>()
+            .init_resource::<HitStop>()
+            .add_systems(Startup, effects::spawn_flash_overlay)
+            .add_systems(
+                Update,
+                (
+                    effects::target_flash_overlay,
+                    effects::apply_gameplay_camera_effects,
+                    (
+                        effects::update_camera_shake,
+                        effects::update_hit_stop,
+                        effects::update_screen_flash,
+                    ),
+                )
+                    .chain()
+                    // 흔들림/플래시는 히트스톱 중에도 움직이도록 실제 시간을 쓰므로, 일시정지는 따로 확인
+                    .run_if(|time: Res<Time<Virtual>>| !time.is_paused()),
+            );
+    }
+}
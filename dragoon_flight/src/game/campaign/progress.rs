@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::save;
+
+const PROGRESS_FILE: &str = "campaign_progress.json";
+
+// 캠페인 진행 상황. 스테이지를 깰 때마다 디스크에 저장된다
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignProgress {
+    pub unlocked: usize,        // 선택 가능한 스테이지 수
+    pub best_scores: Vec<u32>, // 스테이지별 최고 점수 (클리어 기록이 없으면 0)
+}
+
+impl CampaignProgress {
+    fn fresh() -> Self {
+        Self {
+            unlocked: 1,
+            best_scores: Vec::new(),
+        }
+    }
+
+    pub fn is_unlocked(&self, stage: usize) -> bool {
+        stage < self.unlocked
+    }
+
+    pub fn best_score(&self, stage: usize) -> u32 {
+        self.best_scores.get(stage).copied().unwrap_or(0)
+    }
+
+    // 클리어 기록 반영. 다음 스테이지가 새로 열렸으면 true
+    pub fn record_clear(&mut self, stage: usize, score: u32, stage_count: usize) -> bool {
+        if self.best_scores.len() <= stage {
+            self.best_scores.resize(stage + 1, 0);
+        }
+        self.best_scores[stage] = self.best_scores[stage].max(score);
+
+        let next_unlocked = (stage + 2).min(stage_count);
+        let newly_unlocked = next_unlocked > self.unlocked;
+        self.unlocked = self.unlocked.max(next_unlocked);
+        newly_unlocked
+    }
+
+    pub fn save(&self) {
+        match save::write_json(PROGRESS_FILE, self) {
+            Ok(path) => info!("Campaign progress saved to {}", path.display()),
+            Err(err) => error!("Failed to save campaign progress: {}", err),
+        }
+    }
+}
+
+impl FromWorld for CampaignProgress {
+    fn from_world(_world: &mut World) -> Self {
+        match save::read_json(PROGRESS_FILE) {
+            Ok(Some(progress)) => progress,
+            Ok(None) => Self::fresh(),
+            Err(err) => {
+                warn!("Failed to load campaign progress, starting fresh: {}", err);
+                Self::fresh()
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::game::audio::music::MusicCue;
+use crate::game::background::parallax::ParallaxConfig;
+
+// 한 웨이브: 같은 설정의 적 열(column)을 일정 간격으로 내보냄
+#[derive(Debug, Clone)]
+pub struct WaveDef {
+    pub columns: u32,      // 내보낼 열 수
+    pub row_height: f32,   // 열 안의 적 세로 간격
+    pub speed: f32,        // 적 이동 속도(px/s)
+    pub col_spacing: f32,  // 열 사이 가로 간격(px)
+    pub gunner_every: u32, // n칸에 하나꼴로 사격 적 (0이면 없음)
+    pub pause_after: f32,  // 다음 웨이브까지 대기(초)
+}
+
+#[derive(Debug, Clone)]
+pub struct BossDef {
+    pub health: u32,
+    pub scale: f32,
+    pub fire_interval: f32, // 탄막 발사 간격(초)
+    pub spread: u32,        // 한 번에 쏘는 탄 수
+}
+
+#[derive(Debug, Clone)]
+pub struct StageDef {
+    pub name_key: &'static str, // 스테이지 이름 번역 키
+    pub background: ParallaxConfig,
+    pub scroll_speed: f32,
+    pub music: MusicCue,
+    pub waves: Vec<WaveDef>,
+    pub boss: BossDef,
+}
+
+// 캠페인 스테이지 목록 (순서대로 해금)
+#[derive(Resource, Debug, Clone)]
+pub struct Campaign {
+    pub stages: Vec<StageDef>,
+}
+
+impl Campaign {
+    pub fn stage(&self, index: usize) -> Option<&StageDef> {
+        self.stages.get(index)
+    }
+}
+
+fn wave(columns: u32, row_height: f32, speed: f32, gunner_every: u32) -> WaveDef {
+    WaveDef {
+        columns,
+        row_height,
+        speed,
+        col_spacing: 320.0,
+        gunner_every,
+        pause_after: 2.5,
+    }
+}
+
+// 배경 레이어 전체에 색을 입혀 스테이지 분위기를 바꿈
+fn tinted_background(tint: Color) -> ParallaxConfig {
+    let mut config = ParallaxConfig::default();
+    for layer in &mut config.layers {
+        layer.tint = tint;
+    }
+    config
+}
+
+impl Default for Campaign {
+    fn default() -> Self {
+        Self {
+            stages: vec![
+                StageDef {
+                    name_key: "stage-name-outskirts",
+                    background: ParallaxConfig::default(),
+                    scroll_speed: 200.0,
+                    music: MusicCue::InGame,
+                    waves: vec![
+                        wave(4, 180.0, 280.0, 0),
+                        wave(5, 140.0, 300.0, 5),
+                        wave(6, 120.0, 320.0, 4),
+                    ],
+                    boss: BossDef {
+                        health: 60,
+                        scale: 3.0,
+                        fire_interval: 1.6,
+                        spread: 5,
+                    },
+                },
+                StageDef {
+                    name_key: "stage-name-nebula",
+                    background: tinted_background(Color::srgb(0.85, 0.7, 1.0)),
+                    scroll_speed: 240.0,
+                    music: MusicCue::InGame,
+                    waves: vec![
+                        wave(5, 140.0, 320.0, 4),
+                        wave(6, 120.0, 340.0, 3),
+                        wave(6, 100.0, 360.0, 3),
+                        wave(8, 120.0, 380.0, 2),
+                    ],
+                    boss: BossDef {
+                        health: 90,
+                        scale: 3.4,
+                        fire_interval: 1.3,
+                        spread: 7,
+                    },
+                },
+                StageDef {
+                    name_key: "stage-name-core",
+                    background: tinted_background(Color::srgb(1.0, 0.7, 0.65)),
+                    scroll_speed: 300.0,
+                    music: MusicCue::InGame,
+                    waves: vec![
+                        wave(6, 120.0, 380.0, 3),
+                        wave(8, 100.0, 400.0, 2),
+                        wave(8, 100.0, 420.0, 2),
+                        wave(10, 100.0, 440.0, 2),
+                    ],
+                    boss: BossDef {
+                        health: 130,
+                        scale: 3.8,
+                        fire_interval: 1.0,
+                        spread: 9,
+                    },
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use bevy::{ecs::system::SystemId, prelude::*};
+
+// 명령 실행 결과. Ok면 출력할 메시지, Err면 오류 메시지
+pub type ConsoleResult = Result<String, String>;
+
+pub struct ConsoleCommand {
+    pub usage: &'static str,
+    pub completions: &'static [&'static str], // 첫 번째 인자의 탭 완성 후보
+    pub system: SystemId<In<Vec<String>>, ConsoleResult>,
+}
+
+// 이름 → 등록된 명령. 탭 완성과 help가 이름 순으로 보이도록 BTreeMap
+#[derive(Resource, Default)]
+pub struct ConsoleCommands {
+    pub commands: BTreeMap<&'static str, ConsoleCommand>,
+}
+
+pub trait AppConsoleExtensions {
+    // 인자 목록을 받아 결과를 돌려주는 시스템을 콘솔 명령으로 등록
+    fn add_console_command<M>(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        completions: &'static [&'static str],
+        system: impl IntoSystem<In<Vec<String>>, ConsoleResult, M> + 'static,
+    ) -> &mut Self;
+}
+
+impl AppConsoleExtensions for App {
+    fn add_console_command<M>(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        completions: &'static [&'static str],
+        system: impl IntoSystem<In<Vec<String>>, ConsoleResult, M> + 'static,
+    ) -> &mut Self {
+        let world = self.world_mut();
+        let system = world.register_system(system);
+        let mut registry = world.get_resource_or_insert_with(ConsoleCommands::default);
+        if registry
+            .commands
+            .insert(name, ConsoleCommand { usage, completions, system })
+            .is_some()
+        {
+            warn!("Console command '{}' registered twice", name);
+        }
+        self
+    }
+}
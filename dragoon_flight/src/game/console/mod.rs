@@ -0,0 +1,3 @@
+pub mod app_extensions;
+pub mod plugin;
+mod ui;
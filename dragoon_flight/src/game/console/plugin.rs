@@ -0,0 +1,37 @@
+use bevy::{input::InputSystem, prelude::*};
+
+use super::app_extensions::{AppConsoleExtensions, ConsoleCommands, ConsoleResult};
+use super::ui::{self, Console};
+
+// 개발자 콘솔. 디버그 빌드에서만 ` 키로 연다
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Console>()
+            .init_resource::<ConsoleCommands>()
+            .add_systems(Startup, ui::spawn_console)
+            .add_systems(
+                PreUpdate,
+                ui::console_input_system
+                    .after(InputSystem)
+                    .run_if(|| cfg!(debug_assertions)),
+            )
+            .add_systems(Update, (ui::execute_console_commands, ui::console_view_system).chain())
+            .add_console_command("timescale", "timescale <f>", &["0.25", "0.5", "1", "2"], timescale_command);
+    }
+}
+
+// 게임 시간 배속
+fn timescale_command(In(args): In<Vec<String>>, mut time: ResMut<Time<Virtual>>) -> ConsoleResult {
+    let scale: f32 = args
+        .first()
+        .ok_or("usage: timescale <f>")?
+        .parse()
+        .map_err(|_| "timescale must be a number")?;
+    if !(0.0..=10.0).contains(&scale) {
+        return Err("timescale must be between 0 and 10".to_string());
+    }
+    time.set_relative_speed(scale);
+    Ok(format!("timescale {}", scale))
+}
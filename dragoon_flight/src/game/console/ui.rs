@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+
+use super::app_extensions::ConsoleCommands;
+use dragoon_engine::scene::Persistent;
+use crate::game::fonts::registry::FontRole;
+
+// 보관할 출력 줄 수와 화면에 보일 줄 수
+const MAX_OUTPUT: usize = 100;
+const VISIBLE_OUTPUT: usize = 10;
+
+#[derive(Resource, Default)]
+pub struct Console {
+    open: bool,
+    input: String,
+    history: Vec<String>,
+    history_cursor: Option<usize>, // 위/아래로 보고 있는 기록 위치
+    output: VecDeque<String>,
+    pending: Vec<String>, // 실행 대기 중인 입력 줄
+}
+
+impl Console {
+    fn print(&mut self, line: impl Into<String>) {
+        self.output.push_back(line.into());
+        while self.output.len() > MAX_OUTPUT {
+            self.output.pop_front();
+        }
+    }
+
+    fn submit(&mut self) {
+        let line = std::mem::take(&mut self.input).trim().to_string();
+        self.history_cursor = None;
+        if line.is_empty() {
+            return;
+        }
+        if self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+        }
+        self.pending.push(line);
+    }
+
+    fn browse_history(&mut self, older: bool) {
+        if self.history.is_empty() {
+            return;
+        }
+        let last = self.history.len() - 1;
+        self.history_cursor = match (self.history_cursor, older) {
+            (None, true) => Some(last),
+            (None, false) => None,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i < last => Some(i + 1),
+            (Some(_), false) => None,
+        };
+        self.input = self
+            .history_cursor
+            .map_or(String::new(), |i| self.history[i].clone());
+    }
+
+    // 커서 위치(맨 끝) 단어를 명령 이름 또는 첫 번째 인자 후보로 완성
+    fn complete(&mut self, commands: &ConsoleCommands) {
+        let words: Vec<&str> = self.input.split(' ').collect();
+        let (prefix, candidates): (&str, Vec<&str>) = match words.as_slice() {
+            [word] => (word, commands.commands.keys().copied().chain(["help"]).collect()),
+            [name, word] => (
+                word,
+                commands
+                    .commands
+                    .get(name)
+                    .map_or(Vec::new(), |command| command.completions.to_vec()),
+            ),
+            _ => return,
+        };
+        let matches: Vec<&str> = candidates
+            .into_iter()
+            .filter(|c| c.to_lowercase().starts_with(&prefix.to_lowercase()))
+            .collect();
+        let mut listing = None;
+        let completed = match matches.as_slice() {
+            [] => return,
+            [only] => format!("{} ", only),
+            [first, rest @ ..] => {
+                // 후보가 여럿이면 공통 접두어까지만 채우고 후보를 보여줌
+                let common = rest.iter().fold(first.len(), |len, other| {
+                    first
+                        .chars()
+                        .zip(other.chars())
+                        .take(len)
+                        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+                        .count()
+                });
+                listing = Some(matches.join("  "));
+                first[..common].to_string()
+            }
+        };
+        let head_len = self.input.len() - prefix.len();
+        self.input.truncate(head_len);
+        self.input.push_str(&completed);
+        if let Some(listing) = listing {
+            self.print(listing);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ConsoleRoot;
+
+#[derive(Component)]
+pub struct ConsoleOutputText;
+
+#[derive(Component)]
+pub struct ConsoleInputText;
+
+pub fn spawn_console(mut commands: Commands) {
+    commands
+        .spawn((
+            ConsoleRoot,
+            Persistent,
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.85)),
+            GlobalZIndex(i32::MAX - 3),
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ConsoleOutputText,
+                Text::new(""),
+                FontRole::Monospace,
+                TextFont { font_size: 16.0, ..Default::default() },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+            parent.spawn((
+                ConsoleInputText,
+                Text::new("> "),
+                FontRole::Monospace,
+                TextFont { font_size: 18.0, ..Default::default() },
+                TextColor(Color::srgb(0.95, 0.95, 0.6)),
+            ));
+        });
+}
+
+// 콘솔 키 입력. 열려 있는 동안에는 게임 쪽으로 키 입력이 가지 않도록 비움
+pub fn console_input_system(
+    mut console: ResMut<Console>,
+    commands: Res<ConsoleCommands>,
+    mut events: EventReader<KeyboardInput>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+) {
+    let toggled = keys.just_pressed(KeyCode::Backquote);
+    if toggled {
+        console.open = !console.open;
+    }
+    if !console.open {
+        if toggled {
+            keys.reset_all();
+        }
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        if !event.state.is_pressed() || event.key_code == KeyCode::Backquote {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => console.submit(),
+            Key::Escape => console.open = false,
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Tab => console.complete(&commands),
+            Key::ArrowUp => console.browse_history(true),
+            Key::ArrowDown => console.browse_history(false),
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+    keys.reset_all();
+}
+
+// 입력 줄을 실행. 명령 시스템이 월드 전체에 접근할 수 있도록 배타 시스템으로 돈다
+pub fn execute_console_commands(world: &mut World) {
+    let pending = std::mem::take(&mut world.resource_mut::<Console>().pending);
+    for line in pending {
+        let mut words = line.split_whitespace();
+        let Some(name) = words.next() else {
+            continue;
+        };
+        let args: Vec<String> = words.map(str::to_string).collect();
+        let registry = world.resource::<ConsoleCommands>();
+        let reply = if name == "help" {
+            Ok(registry
+                .commands
+                .values()
+                .map(|command| command.usage)
+                .collect::<Vec<_>>()
+                .join("\n"))
+        } else {
+            match registry.commands.get(name).map(|command| command.system) {
+                Some(system) => world
+                    .run_system_with_input(system, args)
+                    .unwrap_or_else(|err| Err(err.to_string())),
+                None => Err(format!("unknown command '{}' (try help)", name)),
+            }
+        };
+        let mut console = world.resource_mut::<Console>();
+        console.print(format!("> {}", line));
+        match reply {
+            Ok(message) if message.is_empty() => {}
+            Ok(message) => console.print(message),
+            Err(message) => console.print(format!("error: {}", message)),
+        }
+    }
+}
+
+pub fn console_view_system(
+    console: Res<Console>,
+    mut roots: Query<&mut Visibility, With<ConsoleRoot>>,
+    mut outputs: Query<&mut Text, (With<ConsoleOutputText>, Without<ConsoleInputText>)>,
+    mut inputs: Query<&mut Text, With<ConsoleInputText>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+    for mut visibility in &mut roots {
+        *visibility = if console.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    let skip = console.output.len().saturating_sub(VISIBLE_OUTPUT);
+    let output: Vec<&str> = console.output.iter().skip(skip).map(String::as_str).collect();
+    for mut text in &mut outputs {
+        text.0 = output.join("\n");
+    }
+    for mut text in &mut inputs {
+        text.0 = format!("> {}_", console.input);
+    }
+}
@@ -0,0 +1,56 @@
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic::{self, PanicHookInfo};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::game::build_info::BuildInfo;
+use crate::game::save;
+
+const CRASH_DIR: &str = "crash_logs";
+
+// 패닉 시 빌드 정보와 백트레이스를 세이브 디렉터리에 남긴다.
+// 기존 훅(표준 에러 출력)은 그대로 호출
+pub fn install_crash_handler(build: BuildInfo) {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        match write_crash_log(&build, info) {
+            Ok(path) => eprintln!("Crash log written to {}", path.display()),
+            Err(err) => eprintln!("Failed to write crash log: {}", err),
+        }
+        previous(info);
+    }));
+}
+
+fn write_crash_log(build: &BuildInfo, info: &PanicHookInfo) -> std::io::Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non-string panic payload>".to_string());
+    let location = info
+        .location()
+        .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+        .unwrap_or_else(|| "<unknown>".to_string());
+    let thread = std::thread::current();
+
+    let mut log = String::new();
+    let _ = writeln!(log, "Dragoon Flight {}", build);
+    let _ = writeln!(log, "time: {} (unix)", timestamp);
+    let _ = writeln!(log, "thread: {}", thread.name().unwrap_or("<unnamed>"));
+    let _ = writeln!(log, "panic: {}", message);
+    let _ = writeln!(log, "at: {}", location);
+    let _ = writeln!(log, "\n{}", Backtrace::force_capture());
+
+    let dir = save::save_dir().join(CRASH_DIR);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("crash-{}.log", timestamp));
+    fs::write(&path, log)?;
+    Ok(path)
+}
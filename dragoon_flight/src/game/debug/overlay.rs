@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use dragoon_engine::scene::Persistent;
+use crate::game::fonts::registry::FontRole;
+
+// 그래프에 남길 프레임 수
+const HISTORY_LEN: usize = 120;
+const GRAPH_WIDTH: f32 = 240.0;
+const GRAPH_HEIGHT: f32 = 40.0;
+// 그래프 세로축 최대값
+const FPS_GRAPH_MAX: f32 = 120.0;
+const FRAME_MS_GRAPH_MAX: f32 = 50.0;
+
+// F3 디버그 오버레이 상태. 씬은 매 프레임 자기 정보를 섹션으로 채운다
+#[derive(Resource, Default)]
+pub struct DebugOverlay {
+    pub enabled: bool,
+    sections: Vec<(&'static str, String)>,
+}
+
+impl DebugOverlay {
+    pub fn set_section(&mut self, name: &'static str, text: String) {
+        match self.sections.iter_mut().find(|(n, _)| *n == name) {
+            Some(section) => section.1 = text,
+            None => self.sections.push((name, text)),
+        }
+    }
+}
+
+// 최근 프레임 시간(초). 히트스톱 영향을 받지 않도록 실제 시간 기준
+#[derive(Resource, Default)]
+pub struct FrameHistory {
+    samples: VecDeque<f32>,
+}
+
+impl FrameHistory {
+    fn average(&self) -> f32 {
+        if self.samples.is_empty() {
+            0.0
+        } else {
+            self.samples.iter().sum::<f32>() / self.samples.len() as f32
+        }
+    }
+
+    fn worst(&self) -> f32 {
+        self.samples.iter().copied().fold(0.0, f32::max)
+    }
+}
+
+#[derive(Component)]
+pub struct DebugOverlayRoot;
+
+#[derive(Component)]
+pub struct DebugOverlayText;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphKind {
+    Fps,
+    FrameTime,
+}
+
+// 그래프 막대. index는 오래된 것부터 0
+#[derive(Component)]
+pub struct GraphBar {
+    kind: GraphKind,
+    index: usize,
+}
+
+pub fn spawn_debug_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            DebugOverlayRoot,
+            Persistent,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(90.0),
+                left: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.65)),
+            GlobalZIndex(i32::MAX - 2),
+            PickingBehavior::IGNORE,
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                DebugOverlayText,
+                Text::new(""),
+                FontRole::Monospace,
+                TextFont { font_size: 14.0, ..Default::default() },
+                TextColor(Color::srgb(0.85, 1.0, 0.85)),
+            ));
+            for kind in [GraphKind::Fps, GraphKind::FrameTime] {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(GRAPH_WIDTH),
+                            height: Val::Px(GRAPH_HEIGHT),
+                            align_items: AlignItems::FlexEnd,
+                            ..Default::default()
+                        },
+                        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+                    ))
+                    .with_children(|parent| {
+                        for index in 0..HISTORY_LEN {
+                            parent.spawn((
+                                GraphBar { kind, index },
+                                Node {
+                                    width: Val::Px(GRAPH_WIDTH / HISTORY_LEN as f32),
+                                    height: Val::Percent(0.0),
+                                    ..Default::default()
+                                },
+                                BackgroundColor(Color::NONE),
+                            ));
+                        }
+                    });
+            }
+        });
+}
+
+pub fn toggle_overlay_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<DebugOverlay>,
+    mut roots: Query<&mut Visibility, With<DebugOverlayRoot>>,
+) {
+    if !keys.just_pressed(KeyCode::F3) {
+        return;
+    }
+    overlay.enabled = !overlay.enabled;
+    for mut visibility in &mut roots {
+        *visibility = if overlay.enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+pub fn record_frame_system(time: Res<Time<Real>>, mut history: ResMut<FrameHistory>) {
+    history.samples.push_back(time.delta_secs());
+    while history.samples.len() > HISTORY_LEN {
+        history.samples.pop_front();
+    }
+}
+
+// 씬이 바뀌면 이전 씬의 섹션이 남지 않도록 매 프레임 비움
+pub fn clear_sections(mut overlay: ResMut<DebugOverlay>) {
+    overlay.sections.clear();
+}
+
+pub fn overlay_text_system(
+    overlay: Res<DebugOverlay>,
+    history: Res<FrameHistory>,
+    mut texts: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    let average = history.average();
+    let fps = if average > 0.0 { 1.0 / average } else { 0.0 };
+    let mut text = format!(
+        "FPS {:.1}\nFrame {:.2} ms (worst {:.2} ms)",
+        fps,
+        average * 1000.0,
+        history.worst() * 1000.0
+    );
+    for (name, body) in &overlay.sections {
+        text.push_str(&format!("\n[{}]\n{}", name, body));
+    }
+    for mut t in &mut texts {
+        t.0.clone_from(&text);
+    }
+}
+
+pub fn overlay_graph_system(
+    history: Res<FrameHistory>,
+    mut bars: Query<(&GraphBar, &mut Node, &mut BackgroundColor)>,
+) {
+    // 막대를 오른쪽 끝에 맞춰 최신 프레임이 오른쪽에 오도록
+    let offset = HISTORY_LEN - history.samples.len();
+    for (bar, mut node, mut color) in &mut bars {
+        let Some(&dt) = bar.index.checked_sub(offset).and_then(|i| history.samples.get(i)) else {
+            node.height = Val::Percent(0.0);
+            continue;
+        };
+        let ms = dt * 1000.0;
+        let fraction = match bar.kind {
+            GraphKind::Fps => (if dt > 0.0 { 1.0 / dt } else { 0.0 }) / FPS_GRAPH_MAX,
+            GraphKind::FrameTime => ms / FRAME_MS_GRAPH_MAX,
+        };
+        node.height = Val::Percent(fraction.clamp(0.0, 1.0) * 100.0);
+        // 60fps 안쪽이면 초록, 30fps 안쪽이면 노랑, 그보다 느리면 빨강
+        color.0 = if ms <= 17.0 {
+            Color::srgb(0.3, 0.9, 0.4)
+        } else if ms <= 34.0 {
+            Color::srgb(0.95, 0.8, 0.3)
+        } else {
+            Color::srgb(0.95, 0.3, 0.3)
+        };
+    }
+}
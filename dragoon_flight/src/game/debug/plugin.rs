@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use super::overlay::{self, DebugOverlay, FrameHistory};
+
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugOverlay>()
+            .init_resource::<FrameHistory>()
+            .add_systems(Startup, overlay::spawn_debug_overlay)
+            .add_systems(PreUpdate, overlay::clear_sections)
+            .add_systems(Update, (overlay::toggle_overlay_system, overlay::record_frame_system))
+            .add_systems(
+                PostUpdate,
+                (overlay::overlay_text_system, overlay::overlay_graph_system)
+                    .run_if(|overlay: Res<DebugOverlay>| overlay.enabled),
+            );
+    }
+}
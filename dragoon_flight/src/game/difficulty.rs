@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use crate::game::events::GameplayEvent;
+
+// 적응형 보정 범위와 변화량
+const PRESSURE_MIN: f32 = 0.6;
+const PRESSURE_MAX: f32 = 1.4;
+const PRESSURE_RECOVERY_PER_SEC: f32 = 0.01;
+const PRESSURE_ON_HURT: f32 = 0.06;
+const PRESSURE_ON_DEATH: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DifficultyLevel {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Insane,
+}
+
+impl DifficultyLevel {
+    pub fn next(self) -> Self {
+        match self {
+            DifficultyLevel::Easy => DifficultyLevel::Normal,
+            DifficultyLevel::Normal => DifficultyLevel::Hard,
+            DifficultyLevel::Hard => DifficultyLevel::Insane,
+            DifficultyLevel::Insane => DifficultyLevel::Easy,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DifficultyLevel::Easy => "Easy",
+            DifficultyLevel::Normal => "Normal",
+            DifficultyLevel::Hard => "Hard",
+            DifficultyLevel::Insane => "Insane",
+        }
+    }
+
+    fn preset(self) -> DifficultyParams {
+        let (enemy_health, enemy_speed, spawn_density, bullet_speed, score_multiplier) = match self {
+            DifficultyLevel::Easy => (0.6, 0.8, 0.75, 0.7, 0.5),
+            DifficultyLevel::Normal => (1.0, 1.0, 1.0, 1.0, 1.0),
+            DifficultyLevel::Hard => (1.4, 1.15, 1.25, 1.3, 1.5),
+            DifficultyLevel::Insane => (2.0, 1.3, 1.5, 1.6, 2.5),
+        };
+        DifficultyParams {
+            enemy_health,
+            enemy_speed,
+            spawn_density,
+            bullet_speed,
+            score_multiplier,
+        }
+    }
+}
+
+// 난이도가 게임 수치에 곱하는 배율
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyParams {
+    pub enemy_health: f32,
+    pub enemy_speed: f32,
+    pub spawn_density: f32, // 클수록 적 열 간격이 촘촘해짐
+    pub bullet_speed: f32,  // 적 탄속
+    pub score_multiplier: f32,
+}
+
+// 선택된 난이도와 적응형 보정 상태
+#[derive(Resource, Debug, Clone)]
+pub struct Difficulty {
+    pub level: DifficultyLevel,
+    pub adaptive: bool,
+    pressure: f32, // 적응형 보정값. 1.0이 중립, 작을수록 쉬워짐
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self {
+            level: DifficultyLevel::default(),
+            adaptive: false,
+            pressure: 1.0,
+        }
+    }
+}
+
+impl Difficulty {
+    // 적응형 모드면 속도/밀도/탄속에 보정값을 곱한다 (체력과 점수 배율은 프리셋 그대로)
+    pub fn params(&self) -> DifficultyParams {
+        let mut params = self.level.preset();
+        if self.adaptive {
+            params.enemy_speed *= self.pressure.sqrt();
+            params.spawn_density *= self.pressure;
+            params.bullet_speed *= self.pressure;
+        }
+        params
+    }
+
+    pub fn reset_pressure(&mut self) {
+        self.pressure = 1.0;
+    }
+
+    pub fn pressure(&self) -> f32 {
+        self.pressure
+    }
+
+    // 스냅샷 복원용
+    pub fn set_pressure(&mut self, pressure: f32) {
+        self.pressure = pressure.clamp(PRESSURE_MIN, PRESSURE_MAX);
+    }
+}
+
+// 최근 피격/사망에 따라 압박을 낮추고, 버티는 동안에는 천천히 올림
+pub fn adaptive_difficulty_system(
+    time: Res<Time>,
+    mut difficulty: ResMut<Difficulty>,
+    mut events: EventReader<GameplayEvent>,
+) {
+    if !difficulty.adaptive {
+        events.clear();
+        return;
+    }
+    let mut pressure = difficulty.pressure + PRESSURE_RECOVERY_PER_SEC * time.delta_secs();
+    for event in events.read() {
+        match event {
+            GameplayEvent::PlayerHurt { .. } => pressure -= PRESSURE_ON_HURT,
+            GameplayEvent::PlayerDied { .. } => pressure -= PRESSURE_ON_DEATH,
+            _ => {}
+        }
+    }
+    difficulty.pressure = pressure.clamp(PRESSURE_MIN, PRESSURE_MAX);
+}
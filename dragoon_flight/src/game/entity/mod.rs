@@ -1,3 +1,2 @@
-pub mod anime;
-pub mod entity_properties;
+pub mod movement;
 pub mod spawner;
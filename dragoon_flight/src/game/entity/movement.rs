@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use dragoon_engine::kinematics::Velocity2D;
+
+use crate::game::input::{self, InputBinding, PlayerId};
+use crate::game::settings::{MovementPreset, Settings};
+
+// 입력을 받을 축
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementAxes {
+    Both,
+    Vertical,
+}
+
+// 플레이어 이동 설정. 속도는 Velocity2D에 누적되어 가감속된다
+#[derive(Component, Debug, Clone)]
+pub struct PlayerMovement {
+    pub speed: f32,        // 기본 최고 속도(px/s)
+    pub focus_speed: f32,  // 포커스(저속) 키를 누르고 있을 때 최고 속도
+    pub acceleration: f32, // 입력이 있을 때 목표 속도로 다가가는 양(px/s^2)
+    pub deceleration: f32, // 입력이 없을 때 멈추는 양(px/s^2). 무한대면 즉시 목표 속도
+    pub axes: MovementAxes,
+    pub bounds: Rect, // 이동 가능 영역(월드 좌표)
+}
+
+impl PlayerMovement {
+    // 플레이필드 안을 자유롭게 이동
+    pub fn free(half_size: Vec2, margin: f32) -> Self {
+        Self {
+            speed: 320.0,
+            focus_speed: 140.0,
+            acceleration: 2400.0,
+            deceleration: 3200.0,
+            axes: MovementAxes::Both,
+            bounds: Rect::from_center_half_size(Vec2::ZERO, half_size - Vec2::splat(margin)),
+        }
+    }
+
+    // 기존 방식: X는 왼쪽 끝에 고정, 상하만 이동
+    pub fn vertical_only(half_size: Vec2, margin: f32) -> Self {
+        let anchor_x = -half_size.x + margin;
+        Self {
+            speed: 300.0,
+            focus_speed: 130.0,
+            acceleration: f32::INFINITY,
+            deceleration: f32::INFINITY,
+            axes: MovementAxes::Vertical,
+            bounds: Rect::new(anchor_x, -half_size.y + margin, anchor_x, half_size.y - margin),
+        }
+    }
+
+    pub fn from_preset(preset: MovementPreset, half_size: Vec2, margin: f32) -> Self {
+        match preset {
+            MovementPreset::Free => Self::free(half_size, margin),
+            MovementPreset::VerticalOnly => Self::vertical_only(half_size, margin),
+        }
+    }
+}
+
+// 플레이어별 입력 매핑으로 이동, 포커스(저속) 키를 누르고 있으면 느리게
+pub fn player_movement_system(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut q: Query<(&PlayerId, &PlayerMovement, &mut Velocity2D, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    let pads = input::ordered_gamepads(&gamepads);
+
+    for (player, movement, mut velocity, mut tf) in &mut q {
+        let binding = InputBinding::for_player(*player, settings.gameplay.coop);
+        let input = input::read_input(binding, &keys, &pads);
+        let focus = input.focus;
+        let mut dir = input.direction;
+        if movement.axes == MovementAxes::Vertical {
+            dir.x = 0.0;
+        }
+        // 대각선과 아날로그 스틱 모두 최고 속도를 넘지 않도록 길이만 제한
+        let dir = dir.clamp_length_max(1.0);
+
+        let max_speed = if focus { movement.focus_speed } else { movement.speed };
+        let target = dir * max_speed;
+        let rate = if dir == Vec2::ZERO {
+            movement.deceleration
+        } else {
+            movement.acceleration
+        };
+        // 무한대 × dt(0) = NaN이므로 무한대 비율은 곱하지 않고 바로 목표 속도로 맞춘다
+        let current = if rate.is_infinite() {
+            target
+        } else {
+            Vec2::new(velocity.x, velocity.y).move_towards(target, rate * dt)
+        };
+
+        let position = tf.translation.truncate() + current * dt;
+        let clamped = position.clamp(movement.bounds.min, movement.bounds.max);
+        // 경계에 막힌 축의 속도는 버림
+        velocity.x = if clamped.x == position.x { current.x } else { 0.0 };
+        velocity.y = if clamped.y == position.y { current.y } else { 0.0 };
+        tf.translation.x = clamped.x;
+        tf.translation.y = clamped.y;
+    }
+}
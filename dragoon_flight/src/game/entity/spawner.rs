@@ -1,7 +1,8 @@
-use super::anime::*;
-use super::entity_properties::*;
 use bevy::prelude::Entity;
 use bevy::prelude::*;
+use dragoon_engine::animation::anime::*;
+use dragoon_engine::collision::Collider;
+use dragoon_engine::kinematics::{Acceleration2D, Velocity2D};
 
 pub fn spawn_entity(
     commands: &mut Commands,
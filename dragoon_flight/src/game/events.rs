@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+// 게임플레이 중 발생하는 이벤트. 오디오/이펙트 등 표현 계층이 구독한다.
+// position은 이벤트가 발생한 월드 좌표
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub enum GameplayEvent {
+    BulletFired { position: Vec2 },
+    EnemyHit { position: Vec2 },
+    EnemyKilled { position: Vec2 },
+    EnemyCleared { position: Vec2 }, // 리스폰 지점 정리로 사라짐. 처치로 치지 않는다
+    PlayerHurt { position: Vec2 },
+    PlayerDied { position: Vec2 },
+}
@@ -0,0 +1,13 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use super::registry::{self, FontRegistry};
+
+pub struct FontsPlugin;
+
+impl Plugin for FontsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FontRegistry>()
+            .add_systems(Update, registry::resolve_fonts)
+            .add_systems(PostUpdate, registry::apply_font_roles.before(UiSystem::Prepare));
+    }
+}
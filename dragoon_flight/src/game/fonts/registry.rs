@@ -0,0 +1,162 @@
+use bevy::{asset::LoadState, prelude::*};
+
+// 텍스트 용도. 엔티티에 붙이면 해당 역할의 글꼴이 TextFont에 채워진다 (기본값 본문)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FontRole {
+    Title, // 화면 제목, 메인 메뉴 버튼
+    #[default]
+    Body,
+    Hud,       // 인게임 점수/시간/안내
+    Monospace, // 콘솔, 디버그 오버레이
+}
+
+impl FontRole {
+    pub const ALL: [FontRole; 4] = [FontRole::Title, FontRole::Body, FontRole::Hud, FontRole::Monospace];
+
+    // 후보 글꼴 경로. 앞에서부터 처음으로 로드된 글꼴을 쓰고, 모두 실패하면 내장 글꼴(FiraMono)
+    fn candidates(self) -> &'static [&'static str] {
+        match self {
+            // 한글 글꼴에 라틴 글자를 합친 번들 글꼴 (assets/fonts/README.md)
+            FontRole::Title | FontRole::Body | FontRole::Hud => &["fonts/DragoonGothic-Regular.ttf"],
+            FontRole::Monospace => &["fonts/FiraMono-Medium.ttf", "fonts/DragoonGothic-Regular.ttf"],
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FontRole::Title => "title",
+            FontRole::Body => "body",
+            FontRole::Hud => "hud",
+            FontRole::Monospace => "monospace",
+        }
+    }
+}
+
+// 역할별 글꼴. 모든 후보의 로딩이 끝나면 역할마다 글꼴을 정하고, 없는 파일은 한 번에 에러로 알린다
+#[derive(Resource)]
+pub struct FontRegistry {
+    files: Vec<(&'static str, Handle<Font>)>,
+    resolved: Option<Vec<(FontRole, Handle<Font>)>>, // None = 아직 로딩 중
+}
+
+impl FromWorld for FontRegistry {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        let mut files: Vec<(&'static str, Handle<Font>)> = Vec::new();
+        for path in FontRole::ALL.iter().flat_map(|role| role.candidates()) {
+            if !files.iter().any(|(loaded, _)| loaded == path) {
+                files.push((path, asset_server.load(*path)));
+            }
+        }
+        Self { files, resolved: None }
+    }
+}
+
+impl FontRegistry {
+    // 역할의 글꼴. 로딩 중이거나 후보가 모두 없으면 내장 글꼴
+    pub fn font(&self, role: FontRole) -> Handle<Font> {
+        self.resolved
+            .as_ref()
+            .and_then(|resolved| resolved.iter().find(|(r, _)| *r == role))
+            .map(|(_, handle)| handle.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.resolved.is_some()
+    }
+
+    // 한글을 그릴 수 있는지. 본문 후보는 모두 한글 글꼴이므로 본문 글꼴이 내장 글꼴이 아니면 된다
+    pub fn has_script_font(&self) -> bool {
+        self.font(FontRole::Body) != Handle::default()
+    }
+
+    fn handle(&self, path: &str) -> Option<&Handle<Font>> {
+        self.files.iter().find(|(p, _)| *p == path).map(|(_, handle)| handle)
+    }
+}
+
+// 후보 중 앞에서부터 처음으로 로드된 글꼴. 모두 실패했으면 None (내장 글꼴)
+fn choose_font<'a>(candidates: &[&'a str], missing: &[&str]) -> Option<&'a str> {
+    candidates.iter().copied().find(|path| !missing.contains(path))
+}
+
+// 후보 글꼴이 모두 로드되거나 실패하면 역할별 글꼴을 정한다
+pub fn resolve_fonts(asset_server: Res<AssetServer>, mut registry: ResMut<FontRegistry>) {
+    if registry.is_settled() {
+        return;
+    }
+    let mut missing = Vec::new();
+    for (path, handle) in &registry.files {
+        match asset_server.load_state(handle) {
+            LoadState::Loaded => {}
+            LoadState::Failed(_) => missing.push(*path),
+            LoadState::NotLoaded | LoadState::Loading => return,
+        }
+    }
+
+    let mut resolved = Vec::new();
+    let mut summary = Vec::new();
+    for role in FontRole::ALL {
+        let chosen = choose_font(role.candidates(), &missing)
+            .and_then(|path| registry.handle(path).map(|handle| (path, handle.clone())));
+        match chosen {
+            Some((path, handle)) => {
+                resolved.push((role, handle));
+                summary.push(format!("{}={}", role.name(), path));
+            }
+            None => summary.push(format!("{}=built-in", role.name())),
+        }
+    }
+    if !missing.is_empty() {
+        error!(
+            "Missing font files under assets/: {}. Falling back to the built-in font where no alternative exists",
+            missing.join(", ")
+        );
+    }
+    info!("Font roles: {}", summary.join(", "));
+    registry.resolved = Some(resolved);
+}
+
+// 역할이 붙은 텍스트에 글꼴 반영. 새로 붙었거나 글꼴이 정해진 프레임에 갱신
+pub fn apply_font_roles(registry: Res<FontRegistry>, mut texts: Query<(Ref<FontRole>, &mut TextFont)>) {
+    let refresh = registry.is_changed();
+    for (role, mut text_font) in &mut texts {
+        if !refresh && !role.is_changed() {
+            continue;
+        }
+        let font = registry.font(*role);
+        if text_font.font != font {
+            text_font.font = font;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANDIDATES: [&str; 3] = ["fonts/A.ttf", "fonts/B.ttf", "fonts/C.ttf"];
+
+    #[test]
+    fn first_loaded_candidate_wins() {
+        assert_eq!(choose_font(&CANDIDATES, &[]), Some("fonts/A.ttf"));
+        assert_eq!(choose_font(&CANDIDATES, &["fonts/A.ttf"]), Some("fonts/B.ttf"));
+        assert_eq!(choose_font(&CANDIDATES, &["fonts/A.ttf", "fonts/B.ttf"]), Some("fonts/C.ttf"));
+    }
+
+    #[test]
+    fn built_in_font_when_all_candidates_fail() {
+        assert_eq!(choose_font(&CANDIDATES, &CANDIDATES), None);
+        assert_eq!(choose_font(&[], &[]), None);
+    }
+
+    #[test]
+    fn bundled_fonts_cover_every_role() {
+        let assets = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+        for role in FontRole::ALL {
+            let first = role.candidates()[0];
+            assert!(assets.join(first).is_file(), "{:?} font {} is not bundled", role, first);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::settings::CoopMode;
+
+pub const MAX_PLAYERS: usize = 2;
+
+// 플레이어 구분. 입력 매핑, HUD, 점수가 이 값을 기준으로 나뉜다
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayerId {
+    One,
+    Two,
+}
+
+impl PlayerId {
+    pub const ALL: [PlayerId; MAX_PLAYERS] = [PlayerId::One, PlayerId::Two];
+
+    pub fn index(self) -> usize {
+        match self {
+            PlayerId::One => 0,
+            PlayerId::Two => 1,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PlayerId::One => "1P",
+            PlayerId::Two => "2P",
+        }
+    }
+
+    // HUD와 기체 표시에 쓰는 색
+    pub fn color(self) -> Color {
+        match self {
+            PlayerId::One => Color::srgb(0.45, 0.8, 1.0),
+            PlayerId::Two => Color::srgb(1.0, 0.65, 0.35),
+        }
+    }
+}
+
+// 키보드 배치. 2인 플레이 시 키보드를 반으로 나눠 쓴다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    Full,   // 방향키 + WASD, 양쪽 Shift
+    Wasd,   // WASD, 왼쪽 Shift
+    Arrows, // 방향키, 오른쪽 Shift
+}
+
+struct KeySet {
+    up: &'static [KeyCode],
+    down: &'static [KeyCode],
+    left: &'static [KeyCode],
+    right: &'static [KeyCode],
+    focus: &'static [KeyCode],
+}
+
+impl KeyboardLayout {
+    fn keys(self) -> KeySet {
+        match self {
+            KeyboardLayout::Full => KeySet {
+                up: &[KeyCode::ArrowUp, KeyCode::KeyW],
+                down: &[KeyCode::ArrowDown, KeyCode::KeyS],
+                left: &[KeyCode::ArrowLeft, KeyCode::KeyA],
+                right: &[KeyCode::ArrowRight, KeyCode::KeyD],
+                focus: &[KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            },
+            KeyboardLayout::Wasd => KeySet {
+                up: &[KeyCode::KeyW],
+                down: &[KeyCode::KeyS],
+                left: &[KeyCode::KeyA],
+                right: &[KeyCode::KeyD],
+                focus: &[KeyCode::ShiftLeft],
+            },
+            KeyboardLayout::Arrows => KeySet {
+                up: &[KeyCode::ArrowUp],
+                down: &[KeyCode::ArrowDown],
+                left: &[KeyCode::ArrowLeft],
+                right: &[KeyCode::ArrowRight],
+                focus: &[KeyCode::ShiftRight],
+            },
+        }
+    }
+}
+
+// 플레이어 하나의 입력 장치 매핑. 키보드와 게임패드를 함께 쓸 수 있다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputBinding {
+    pub keyboard: Option<KeyboardLayout>,
+    pub gamepad: Option<usize>, // 연결 순서 기준 게임패드 번호
+}
+
+impl InputBinding {
+    pub fn for_player(player: PlayerId, coop: CoopMode) -> Self {
+        match (coop, player) {
+            (CoopMode::Solo, _) => Self {
+                keyboard: Some(KeyboardLayout::Full),
+                gamepad: Some(0),
+            },
+            (CoopMode::KeyboardSplit, PlayerId::One) => Self {
+                keyboard: Some(KeyboardLayout::Wasd),
+                gamepad: Some(0),
+            },
+            (CoopMode::KeyboardSplit, PlayerId::Two) => Self {
+                keyboard: Some(KeyboardLayout::Arrows),
+                gamepad: Some(1),
+            },
+            (CoopMode::Gamepads, PlayerId::One) => Self {
+                keyboard: Some(KeyboardLayout::Full),
+                gamepad: Some(0),
+            },
+            (CoopMode::Gamepads, PlayerId::Two) => Self {
+                keyboard: None,
+                gamepad: Some(1),
+            },
+        }
+    }
+}
+
+// 한 프레임의 이동 입력
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PlayerInput {
+    pub direction: Vec2, // 각 축 -1.0 ~ 1.0
+    pub focus: bool,
+}
+
+const STICK_DEADZONE: f32 = 0.2;
+
+pub fn read_input(binding: InputBinding, keys: &ButtonInput<KeyCode>, gamepads: &[&Gamepad]) -> PlayerInput {
+    let mut input = PlayerInput::default();
+    if let Some(layout) = binding.keyboard {
+        let set = layout.keys();
+        if keys.any_pressed(set.up.iter().copied()) {
+            input.direction.y += 1.0;
+        }
+        if keys.any_pressed(set.down.iter().copied()) {
+            input.direction.y -= 1.0;
+        }
+        if keys.any_pressed(set.right.iter().copied()) {
+            input.direction.x += 1.0;
+        }
+        if keys.any_pressed(set.left.iter().copied()) {
+            input.direction.x -= 1.0;
+        }
+        input.focus |= keys.any_pressed(set.focus.iter().copied());
+    }
+    if let Some(gamepad) = binding.gamepad.and_then(|index| gamepads.get(index)) {
+        let stick = gamepad.left_stick();
+        if stick.length() > STICK_DEADZONE {
+            input.direction += stick;
+        }
+        input.direction += gamepad.dpad();
+        input.focus |= gamepad.any_pressed([GamepadButton::LeftTrigger, GamepadButton::RightTrigger]);
+    }
+    input.direction = input.direction.clamp(Vec2::NEG_ONE, Vec2::ONE);
+    input
+}
+
+// 게임패드를 연결 순서(엔티티 순)로 정렬. InputBinding의 게임패드 번호가 이 순서를 따른다
+pub fn ordered_gamepads<'a>(gamepads: &'a Query<(Entity, &Gamepad)>) -> Vec<&'a Gamepad> {
+    let mut pads: Vec<(Entity, &Gamepad)> = gamepads.iter().collect();
+    pads.sort_by_key(|(entity, _)| *entity);
+    pads.into_iter().map(|(_, pad)| pad).collect()
+}
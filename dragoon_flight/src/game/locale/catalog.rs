@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use fluent_bundle::{FluentArgs, FluentResource, concurrent::FluentBundle};
+use unic_langid::LanguageIdentifier;
+
+use super::ftl::FtlSource;
+use super::language::Language;
+use crate::game::fonts::registry::FontRegistry;
+use crate::game::settings::Settings;
+
+// 언어별 번역 번들. 언어나 번역이 바뀌면 Localized 텍스트가 다시 번역된다
+#[derive(Resource)]
+pub struct Locale {
+    requested: Language, // 설정에서 고른 언어
+    active: Language,    // 실제로 표시 중인 언어 (글꼴이 없으면 영어로 대체)
+    sources: Vec<(Language, Handle<FtlSource>)>,
+    bundles: HashMap<Language, FluentBundle<FluentResource>>,
+}
+
+impl FromWorld for Locale {
+    fn from_world(world: &mut World) -> Self {
+        let requested = world.get_resource::<Settings>().map(|s| s.language).unwrap_or_default();
+        let asset_server = world.resource::<AssetServer>();
+        let sources = Language::ALL
+            .into_iter()
+            .map(|language| (language, asset_server.load(format!("locale/{}/ui.ftl", language.code()))))
+            .collect();
+        Self {
+            requested,
+            active: requested,
+            sources,
+            bundles: HashMap::new(),
+        }
+    }
+}
+
+impl Locale {
+    pub fn language(&self) -> Language {
+        self.active
+    }
+
+    // 현재 언어 → 영어 순으로 찾고, 어디에도 없으면 키를 그대로 보여준다
+    pub fn format(&self, key: &str, args: &[(&'static str, String)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        for language in [self.active, Language::English] {
+            let Some(bundle) = self.bundles.get(&language) else {
+                continue;
+            };
+            let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) else {
+                continue;
+            };
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+            if !errors.is_empty() {
+                warn!("Translation '{}' ({}): {:?}", key, language.code(), errors);
+            }
+            return text.into_owned();
+        }
+        // 번들이 아직 로딩 중이면 조용히 넘어가고, 로딩 후 다시 번역된다
+        if self.bundles.contains_key(&Language::English) {
+            warn!("Missing translation '{}'", key);
+        }
+        key.to_string()
+    }
+
+    fn set_bundle(&mut self, language: Language, source: &str) {
+        let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, errors)| {
+            warn!("Syntax errors in {} translations: {:?}", language.code(), errors);
+            resource
+        });
+        let langid: LanguageIdentifier = language.code().parse().expect("language codes are valid identifiers");
+        let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+        // 방향 격리 문자(FSI/PDI)는 게임 글꼴에 없으므로 넣지 않는다
+        bundle.set_use_isolating(false);
+        if let Err(errors) = bundle.add_resource(resource) {
+            warn!("Duplicate {} translations: {:?}", language.code(), errors);
+        }
+        self.bundles.insert(language, bundle);
+        info!("Loaded {} translations", language.code());
+    }
+}
+
+// 설정의 언어가 바뀌면 반영. 한글 글꼴을 하나도 불러오지 못했으면 영어로 대신 표시
+// (글꼴 로딩이 끝나기 전에는 요청한 언어 그대로)
+pub fn sync_language(settings: Res<Settings>, fonts: Res<FontRegistry>, mut locale: ResMut<Locale>) {
+    let requested = settings.language;
+    let active = if requested.needs_script_font() && fonts.is_settled() && !fonts.has_script_font() {
+        Language::English
+    } else {
+        requested
+    };
+    if locale.requested == requested && locale.active == active {
+        return;
+    }
+    if active != requested {
+        warn!("No font for {} text; falling back to English", requested.code());
+    }
+    locale.requested = requested;
+    locale.active = active;
+}
+
+// 번역 파일이 로드되거나 (핫 리로드로) 바뀌면 번들을 다시 만든다
+pub fn load_bundles(
+    mut events: EventReader<AssetEvent<FtlSource>>,
+    sources: Res<Assets<FtlSource>>,
+    mut locale: ResMut<Locale>,
+) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event else {
+            continue;
+        };
+        let Some(language) = locale.sources.iter().find(|(_, handle)| handle.id() == *id).map(|(l, _)| *l) else {
+            continue;
+        };
+        if let Some(source) = sources.get(*id) {
+            locale.set_bundle(language, &source.0);
+        }
+    }
+}
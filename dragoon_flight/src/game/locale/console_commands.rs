@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::catalog::Locale;
+use super::language::Language;
+use crate::game::console::app_extensions::{AppConsoleExtensions, ConsoleResult};
+use crate::game::settings::Settings;
+
+pub fn add_console_commands(app: &mut App) {
+    app.add_console_command("lang", "lang [en|ko]", &["en", "ko"], lang_command);
+}
+
+// UI 언어 확인/변경
+fn lang_command(In(args): In<Vec<String>>, mut settings: ResMut<Settings>, locale: Res<Locale>) -> ConsoleResult {
+    let Some(code) = args.first() else {
+        // 글꼴이 없어 영어로 대체 중이면 함께 알려준다
+        let shown = locale.language();
+        return Ok(if shown == settings.language {
+            format!("lang {}", shown.code())
+        } else {
+            format!("lang {} (showing {}: no font)", settings.language.code(), shown.code())
+        });
+    };
+    let language = Language::from_code(code).ok_or_else(|| {
+        let known: Vec<&str> = Language::ALL.iter().map(|l| l.code()).collect();
+        format!("unknown language '{}' (known: {})", code, known.join(", "))
+    })?;
+    settings.language = language;
+    Ok(format!("lang {}", language.code()))
+}
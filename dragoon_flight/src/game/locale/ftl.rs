@@ -0,0 +1,32 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+
+// .ftl 파일 원문. 파싱은 언어별 번들을 만들 때 한다
+#[derive(Asset, TypePath, Debug)]
+pub struct FtlSource(pub String);
+
+#[derive(Default)]
+pub struct FtlLoader;
+
+impl AssetLoader for FtlLoader {
+    type Asset = FtlSource;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<FtlSource, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        String::from_utf8(bytes).map(FtlSource).map_err(std::io::Error::other)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
@@ -0,0 +1,34 @@
+// 지원 언어. 새 언어는 assets/locale/<code>/ui.ftl 과 함께 추가한다
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Korean,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Korean];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Korean => "ko",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Language::English => Language::Korean,
+            Language::Korean => Language::English,
+        }
+    }
+
+    // 내장 폰트에 없는 글자(한글)를 쓰는 언어. 폰트 체인에서 글꼴을 찾지 못하면 영어로 대신 표시
+    pub fn needs_script_font(self) -> bool {
+        matches!(self, Language::Korean)
+    }
+}
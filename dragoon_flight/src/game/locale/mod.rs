@@ -0,0 +1,6 @@
+pub mod catalog;
+pub mod ftl;
+pub mod language;
+pub mod plugin;
+pub mod text;
+mod console_commands;
@@ -0,0 +1,20 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use super::catalog::{self, Locale};
+use super::console_commands;
+use super::ftl::{FtlLoader, FtlSource};
+use super::text;
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<FtlSource>()
+            .init_asset_loader::<FtlLoader>()
+            .init_resource::<Locale>()
+            .add_systems(Update, (catalog::sync_language, catalog::load_bundles))
+            // 씬 시스템이 바꾼 키/인자를 같은 프레임의 UI 레이아웃 전에 반영
+            .add_systems(PostUpdate, text::apply_localized_text.before(UiSystem::Prepare));
+        console_commands::add_console_commands(app);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+use super::catalog::Locale;
+use crate::game::fonts::registry::FontRole;
+
+// 번역 키와 인자. 키/인자나 언어가 바뀌면 Text가 다시 채워진다. 글꼴은 FontRole(기본 본문)이 정한다
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(Text, FontRole)]
+pub struct Localized {
+    pub key: &'static str,
+    pub args: Vec<(&'static str, String)>,
+    pub key_args: Vec<(&'static str, &'static str)>, // 값도 번역 키인 인자 (스테이지 이름 등)
+}
+
+impl Localized {
+    pub fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            args: Vec::new(),
+            key_args: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    // 현재 언어로 번역한 뒤 넣는 인자
+    pub fn with_key(mut self, name: &'static str, key: &'static str) -> Self {
+        self.key_args.push((name, key));
+        self
+    }
+
+    pub fn format(&self, locale: &Locale) -> String {
+        let mut args = self.args.clone();
+        args.extend(self.key_args.iter().map(|(name, key)| (*name, locale.format(key, &[]))));
+        locale.format(self.key, &args)
+    }
+}
+
+pub fn apply_localized_text(locale: Res<Locale>, mut texts: Query<(Ref<Localized>, &mut Text)>) {
+    let relocalize = locale.is_changed();
+    for (localized, mut text) in &mut texts {
+        if !relocalize && !localized.is_changed() {
+            continue;
+        }
+        let value = localized.format(&locale);
+        if text.0 != value {
+            text.0 = value;
+        }
+    }
+}
@@ -1,2 +1,22 @@
+pub mod audio;
+pub mod background;
+pub mod build_info;
+pub mod camera;
+pub mod campaign;
+pub mod console;
+pub mod crash;
+pub mod debug;
+pub mod difficulty;
+pub mod events;
+pub mod fonts;
+pub mod input;
+pub mod locale;
+pub mod net;
+pub mod playfield;
+pub mod run;
+pub mod save;
 pub mod scene_manager;
+pub mod settings;
+pub mod survival;
+pub mod vfx;
 mod entity;
@@ -0,0 +1,70 @@
+use std::net::SocketAddr;
+
+use bevy::prelude::*;
+
+use crate::game::input::PlayerId;
+
+// 넷플레이(간이 대전 모드, net::sim 참고) 실행 옵션. 같은 PC에서 두 인스턴스로 시험하려면:
+//   dragoon_flight --net-bind 127.0.0.1:7000 --net-peer 127.0.0.1:7001 --net-player 1
+//   dragoon_flight --net-bind 127.0.0.1:7001 --net-peer 127.0.0.1:7000 --net-player 2
+#[derive(Resource, Debug, Clone)]
+pub struct NetConfig {
+    pub bind: SocketAddr,
+    pub peer: SocketAddr,
+    pub local_player: PlayerId,
+    pub input_delay: u32, // 로컬 입력 지연(틱). 클수록 롤백이 줄어듦
+}
+
+const DEFAULT_INPUT_DELAY: u32 = 2;
+
+impl NetConfig {
+    // --net-bind와 --net-peer가 모두 있으면 넷플레이로 시작
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Option<Self> {
+        let mut bind = None;
+        let mut peer = None;
+        let mut local_player = PlayerId::One;
+        let mut input_delay = DEFAULT_INPUT_DELAY;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let value = match arg.as_str() {
+                "--net-bind" | "--net-peer" | "--net-player" | "--net-delay" => args.next(),
+                _ => continue,
+            };
+            let Some(value) = value else {
+                warn!("Missing value for {}", arg);
+                continue;
+            };
+            match arg.as_str() {
+                "--net-bind" => bind = parse_addr(&arg, &value),
+                "--net-peer" => peer = parse_addr(&arg, &value),
+                "--net-player" => match value.as_str() {
+                    "1" => local_player = PlayerId::One,
+                    "2" => local_player = PlayerId::Two,
+                    _ => warn!("Invalid --net-player '{}', expected 1 or 2", value),
+                },
+                _ => match value.parse() {
+                    Ok(delay) => input_delay = delay,
+                    Err(_) => warn!("Invalid --net-delay '{}'", value),
+                },
+            }
+        }
+
+        Some(Self {
+            bind: bind?,
+            peer: peer?,
+            local_player,
+            input_delay,
+        })
+    }
+}
+
+fn parse_addr(arg: &str, value: &str) -> Option<SocketAddr> {
+    match value.parse() {
+        Ok(addr) => Some(addr),
+        Err(err) => {
+            warn!("Invalid address for {} '{}': {}", arg, value, err);
+            None
+        }
+    }
+}
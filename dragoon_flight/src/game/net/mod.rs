@@ -0,0 +1,7 @@
+pub mod config;
+pub mod plugin;
+pub mod protocol;
+pub mod scene;
+pub mod session;
+pub mod sim;
+pub mod transport;
@@ -0,0 +1,93 @@
+use std::io;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use super::config::NetConfig;
+use super::protocol::NetInput;
+use super::session::RollbackSession;
+use super::sim::{NetSim, TICK_RATE};
+use super::transport::UdpTransport;
+use crate::game::input::{self, InputBinding, PlayerId};
+use super::scene::{NET_PLAY, NetPlayScene};
+use dragoon_engine::scene::{AppSceneExtensions, SceneStatus};
+use crate::game::scene_manager::scene_ids;
+use crate::game::settings::CoopMode;
+
+// 진행 중인 넷플레이 세션. NetPlay 씬에 들어갈 때 만들고 나갈 때 제거
+#[derive(Resource)]
+pub struct NetSession {
+    pub session: RollbackSession<NetSim>,
+    pub local_player: PlayerId,
+    transport: UdpTransport,
+    restore_timestep: Duration, // 세션 전의 고정 틱 간격. 세션이 끝나면 되돌린다
+}
+
+impl NetSession {
+    // 세션 동안 고정 틱을 시뮬레이션 틱 속도로 맞춘다
+    pub fn start(config: &NetConfig, fixed_time: &mut Time<Fixed>) -> io::Result<Self> {
+        let transport = UdpTransport::bind(config.bind, config.peer)?;
+        let restore_timestep = fixed_time.timestep();
+        fixed_time.set_timestep_hz(TICK_RATE as f64);
+        info!(
+            "Net session on {} <-> {} as {}",
+            config.bind,
+            config.peer,
+            config.local_player.label()
+        );
+        Ok(Self {
+            session: RollbackSession::new(config.local_player, NetSim::default(), config.input_delay),
+            local_player: config.local_player,
+            transport,
+            restore_timestep,
+        })
+    }
+
+    pub fn finish(&self, fixed_time: &mut Time<Fixed>) {
+        fixed_time.set_timestep(self.restore_timestep);
+    }
+}
+
+pub struct NetPlugin;
+
+impl Plugin for NetPlugin {
+    fn build(&self, app: &mut App) {
+        // 넷플레이 시뮬레이션은 고정 틱으로 진행 (틱 시스템은 NetPlay 씬이 FixedUpdate에 등록,
+        // 틱 속도는 세션이 있는 동안만 NetSession이 맞춤)
+        app.add_scene(NetPlayScene);
+
+        if let Some(config) = NetConfig::from_args(std::env::args().skip(1)) {
+            app.insert_resource(config)
+                .allow_transition(scene_ids::MAIN, NET_PLAY)
+                .add_systems(Startup, enter_net_play);
+        }
+    }
+}
+
+fn enter_net_play(mut next_state: ResMut<NextState<SceneStatus>>) {
+    next_state.set(NET_PLAY);
+}
+
+// 한 틱: 로컬 입력 기록 → 상대 패킷 반영(필요하면 롤백) → 진행 → 입력/체크섬 전송
+pub fn net_tick_system(
+    mut net: ResMut<NetSession>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    let NetSession {
+        session, transport, ..
+    } = &mut *net;
+
+    // 넷플레이에서는 각자 자기 PC의 키보드 전체와 첫 게임패드를 씀
+    let binding = InputBinding::for_player(PlayerId::One, CoopMode::Solo);
+    let local = input::read_input(binding, &keys, &input::ordered_gamepads(&gamepads));
+    session.add_local_input(NetInput::from_player_input(&local));
+
+    for message in transport.receive() {
+        session.receive(message);
+    }
+    session.advance();
+    for message in session.drain_outgoing() {
+        transport.send(&message);
+    }
+}
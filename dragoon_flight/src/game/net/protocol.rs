@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use crate::game::input::PlayerInput;
+
+// 한 틱의 입력. 비트 플래그로 1바이트에 담아 주고받는다
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NetInput(pub u8);
+
+impl NetInput {
+    pub const UP: u8 = 1 << 0;
+    pub const DOWN: u8 = 1 << 1;
+    pub const LEFT: u8 = 1 << 2;
+    pub const RIGHT: u8 = 1 << 3;
+    pub const FOCUS: u8 = 1 << 4;
+
+    pub fn from_player_input(input: &PlayerInput) -> Self {
+        let mut bits = 0;
+        if input.direction.y > 0.5 {
+            bits |= Self::UP;
+        }
+        if input.direction.y < -0.5 {
+            bits |= Self::DOWN;
+        }
+        if input.direction.x < -0.5 {
+            bits |= Self::LEFT;
+        }
+        if input.direction.x > 0.5 {
+            bits |= Self::RIGHT;
+        }
+        if input.focus {
+            bits |= Self::FOCUS;
+        }
+        Self(bits)
+    }
+
+    pub fn pressed(self, flag: u8) -> bool {
+        self.0 & flag != 0
+    }
+
+    // 정수 방향 (-1, 0, 1)
+    pub fn direction(self) -> IVec2 {
+        IVec2::new(
+            self.pressed(Self::RIGHT) as i32 - self.pressed(Self::LEFT) as i32,
+            self.pressed(Self::UP) as i32 - self.pressed(Self::DOWN) as i32,
+        )
+    }
+}
+
+// 한 패킷에 담는 최대 입력 수 (재전송 포함)
+pub const MAX_INPUTS_PER_PACKET: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetMessage {
+    // start_frame부터 이어지는 로컬 입력들과, 상대 입력을 어디까지 받았는지(ack)
+    Input {
+        start_frame: u32,
+        ack: u32,
+        inputs: Vec<NetInput>,
+    },
+    // 양쪽 입력이 모두 확정된 프레임의 상태 체크섬
+    Checksum { frame: u32, value: u64 },
+}
+
+const TAG_INPUT: u8 = 1;
+const TAG_CHECKSUM: u8 = 2;
+
+impl NetMessage {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        match self {
+            NetMessage::Input {
+                start_frame,
+                ack,
+                inputs,
+            } => {
+                let count = inputs.len().min(MAX_INPUTS_PER_PACKET);
+                buf.push(TAG_INPUT);
+                buf.extend_from_slice(&start_frame.to_le_bytes());
+                buf.extend_from_slice(&ack.to_le_bytes());
+                buf.push(count as u8);
+                buf.extend(inputs[..count].iter().map(|input| input.0));
+            }
+            NetMessage::Checksum { frame, value } => {
+                buf.push(TAG_CHECKSUM);
+                buf.extend_from_slice(&frame.to_le_bytes());
+                buf.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    // 형식이 맞지 않는 패킷은 None
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let (&tag, rest) = bytes.split_first()?;
+        match tag {
+            TAG_INPUT => {
+                let start_frame = u32::from_le_bytes(rest.get(0..4)?.try_into().ok()?);
+                let ack = u32::from_le_bytes(rest.get(4..8)?.try_into().ok()?);
+                let count = *rest.get(8)? as usize;
+                let inputs = rest.get(9..9 + count)?.iter().map(|&bits| NetInput(bits)).collect();
+                Some(NetMessage::Input {
+                    start_frame,
+                    ack,
+                    inputs,
+                })
+            }
+            TAG_CHECKSUM => {
+                let frame = u32::from_le_bytes(rest.get(0..4)?.try_into().ok()?);
+                let value = u64::from_le_bytes(rest.get(4..12)?.try_into().ok()?);
+                Some(NetMessage::Checksum { frame, value })
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::{ecs::schedule::SystemConfigs, prelude::*};
+
+use super::config::NetConfig;
+use super::plugin::{self as net_plugin, NetSession};
+use super::sim::{self, NetSim};
+use crate::game::background::parallax::{self, ParallaxConfig, ScrollPacing};
+use crate::game::fonts::registry::FontRole;
+use crate::game::input::PlayerId;
+use crate::game::locale::catalog::Locale;
+use crate::game::playfield::{self, Playfield};
+use crate::game::scene_manager::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStatus};
+
+// NetPlugin이 등록하는 씬
+pub const NET_PLAY: SceneStatus = SceneStatus::new("NetPlay");
+
+// 넷플레이 대전 화면. 시뮬레이션(NetSim, InGame과 별개의 대전 모드)은 NetPlugin이 고정 틱으로 돌리고,
+// 여기서는 그 상태를 그리기만 한다
+pub struct NetPlayScene;
+
+impl IScene for NetPlayScene {
+    fn state(&self) -> SceneStatus {
+        NET_PLAY
+    }
+
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::MAIN]
+    }
+
+    fn system_on_enter(&self) -> SystemConfigs {
+        setup_net_play.into_configs()
+    }
+
+    fn system_on_update(&self) -> SystemConfigs {
+        (
+            net_view_system,
+            net_hud_system,
+            net_exit_system,
+            parallax::scroll_parallax_system,
+        )
+            .into_configs()
+    }
+
+    fn system_on_fixed_update(&self) -> Option<SystemConfigs> {
+        Some(net_plugin::net_tick_system.run_if(resource_exists::<NetSession>))
+    }
+
+    fn system_on_exit(&self) -> Option<SystemConfigs> {
+        Some(cleanup_net_play.into_configs())
+    }
+}
+
+#[derive(Component)]
+struct NetShip(usize);
+
+#[derive(Component)]
+struct NetHudText;
+
+// 기체 위 이름표. 로컬 플레이어는 표시를 덧붙이므로 번역이 필요
+#[derive(Component)]
+struct NetShipLabel {
+    player: PlayerId,
+    local: bool,
+}
+
+// 시뮬레이션 객체 id → 화면 엔티티
+#[derive(Resource)]
+struct NetView {
+    enemies: HashMap<u32, Entity>,
+    shots: HashMap<u32, Entity>,
+    enemy_image: Handle<Image>,
+    shot_image: Handle<Image>,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn setup_net_play(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    playfield: Res<Playfield>,
+    parallax_config: Res<ParallaxConfig>,
+    mut pacing: ResMut<ScrollPacing>,
+    config: Option<Res<NetConfig>>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+) {
+    let Some(config) = config else {
+        warn!("NetPlay entered without net config");
+        next_state.set(scene_ids::MAIN);
+        return;
+    };
+    match NetSession::start(&config, &mut fixed_time) {
+        Ok(session) => commands.insert_resource(session),
+        Err(err) => {
+            error!("Failed to start net session on {}: {}", config.bind, err);
+            next_state.set(scene_ids::MAIN);
+            return;
+        }
+    }
+
+    commands.spawn(playfield::playfield_camera(&playfield));
+    parallax::spawn_parallax_layers(&mut commands, &asset_server, &parallax_config, playfield.size);
+    pacing.speed = 220.0;
+
+    let ship_image: Handle<Image> = asset_server.load("anime/protagonist_1.png");
+    for player in PlayerId::ALL {
+        commands
+            .spawn((
+                NetShip(player.index()),
+                Sprite::from_image(ship_image.clone()),
+                Transform::from_xyz(0.0, 0.0, 0.5),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    NetShipLabel {
+                        player,
+                        local: player == config.local_player,
+                    },
+                    Text2d::new(player.label()),
+                    FontRole::Hud,
+                    TextFont { font_size: 16.0, ..Default::default() },
+                    TextColor(player.color()),
+                    Transform::from_xyz(0.0, 40.0, 0.1),
+                ));
+            });
+    }
+
+    commands.spawn((
+        NetHudText,
+        Text::new(""),
+        FontRole::Hud,
+        TextFont { font_size: 22.0, ..Default::default() },
+        TextColor(Color::srgb(0.95, 0.95, 0.95)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..Default::default()
+        },
+    ));
+
+    commands.insert_resource(NetView {
+        enemies: HashMap::new(),
+        shots: HashMap::new(),
+        enemy_image: asset_server.load("anime/enemy_1.png"),
+        shot_image: asset_server.load("anime/bullet_1.png"),
+    });
+}
+
+// 현재(예측 포함) 시뮬레이션 상태를 스프라이트에 반영
+fn net_view_system(
+    mut commands: Commands,
+    net: Option<Res<NetSession>>,
+    view: Option<ResMut<NetView>>,
+    mut ships: Query<(&NetShip, &mut Transform, &mut Visibility, &mut Sprite)>,
+    mut sprites: Query<&mut Transform, Without<NetShip>>,
+) {
+    let (Some(net), Some(mut view)) = (net, view) else {
+        return;
+    };
+    let state: &NetSim = net.session.state();
+
+    for (NetShip(index), mut tf, mut visibility, mut sprite) in &mut ships {
+        let ship = &state.ships[*index];
+        let pos = sim::to_world(ship.pos);
+        tf.translation.x = pos.x;
+        tf.translation.y = pos.y;
+        *visibility = if ship.alive() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        // 무적 중에는 깜빡임
+        let blink = ship.invulnerable > 0 && (ship.invulnerable / 6).is_multiple_of(2);
+        sprite.color.set_alpha(if blink { 0.3 } else { 1.0 });
+    }
+
+    let NetView {
+        enemies,
+        shots,
+        enemy_image,
+        shot_image,
+    } = &mut *view;
+    sync_sprites(
+        &mut commands,
+        enemies,
+        state.enemies.iter().map(|enemy| (enemy.id, sim::to_world(enemy.pos))),
+        enemy_image,
+        &mut sprites,
+    );
+    sync_sprites(
+        &mut commands,
+        shots,
+        state.shots.iter().map(|shot| (shot.id, sim::to_world(shot.pos))),
+        shot_image,
+        &mut sprites,
+    );
+}
+
+// id별로 스프라이트를 만들고 옮기고, 사라진 것은 제거
+fn sync_sprites(
+    commands: &mut Commands,
+    map: &mut HashMap<u32, Entity>,
+    items: impl Iterator<Item = (u32, Vec2)>,
+    image: &Handle<Image>,
+    sprites: &mut Query<&mut Transform, Without<NetShip>>,
+) {
+    let mut seen = HashSet::new();
+    for (id, pos) in items {
+        seen.insert(id);
+        match map.get(&id).and_then(|&entity| sprites.get_mut(entity).ok()) {
+            Some(mut tf) => {
+                tf.translation.x = pos.x;
+                tf.translation.y = pos.y;
+            }
+            None if !map.contains_key(&id) => {
+                let entity = commands
+                    .spawn((
+                        Sprite::from_image(image.clone()),
+                        Transform::from_translation(pos.extend(0.2)),
+                    ))
+                    .id();
+                map.insert(id, entity);
+            }
+            // 이번 프레임에 막 생성되어 아직 쿼리에 없음
+            None => {}
+        }
+    }
+    map.retain(|id, entity| {
+        let keep = seen.contains(id);
+        if !keep {
+            commands.entity(*entity).despawn_recursive();
+        }
+        keep
+    });
+}
+
+// 매 프레임 값이 바뀌므로 Localized 대신 직접 번역해 조립
+fn net_hud_system(
+    net: Option<Res<NetSession>>,
+    locale: Res<Locale>,
+    mut texts: Query<&mut Text, With<NetHudText>>,
+    mut labels: Query<(Ref<NetShipLabel>, &mut Text2d)>,
+) {
+    let Some(net) = net else {
+        return;
+    };
+    let state = net.session.state();
+    let stats = net.session.stats();
+    let mut players = Vec::new();
+    for player in PlayerId::ALL {
+        let ship = &state.ships[player.index()];
+        let marker = if player == net.local_player { "*" } else { " " };
+        players.push(locale.format(
+            "net-hud-player",
+            &[
+                ("marker", marker.to_string()),
+                ("player", player.label().to_string()),
+                ("score", format!("{:06}", ship.score)),
+                ("hp", ship.hp.to_string()),
+            ],
+        ));
+    }
+    let mut lines = vec![
+        players.join("    "),
+        locale.format(
+            "net-hud-frame",
+            &[
+                ("frame", net.session.frame().to_string()),
+                ("rollbacks", stats.rollbacks.to_string()),
+                ("last", stats.last_rollback.to_string()),
+            ],
+        ),
+    ];
+    if stats.stalled {
+        lines.push(locale.format("net-hud-waiting", &[]));
+    }
+    if let Some(frame) = net.session.desync() {
+        lines.push(locale.format("net-hud-desync", &[("frame", frame.to_string())]));
+    }
+    let hud = lines.join("\n");
+    for mut text in &mut texts {
+        if text.0 != hud {
+            text.0 = hud.clone();
+        }
+    }
+
+    for (label, mut text) in &mut labels {
+        if !locale.is_changed() && !label.is_added() {
+            continue;
+        }
+        let value = if label.local {
+            locale.format("net-you", &[("player", label.player.label().to_string())])
+        } else {
+            label.player.label().to_string()
+        };
+        if text.0 != value {
+            text.0 = value;
+        }
+    }
+}
+
+fn net_exit_system(keys: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<SceneStatus>>) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(scene_ids::MAIN);
+    }
+}
+
+fn cleanup_net_play(mut commands: Commands, net: Option<Res<NetSession>>, mut fixed_time: ResMut<Time<Fixed>>) {
+    if let Some(net) = net {
+        net.finish(&mut fixed_time);
+    }
+    commands.remove_resource::<NetSession>();
+    commands.remove_resource::<NetView>();
+    // 메뉴로 나간 뒤에는 다시 접속하지 않음
+    commands.remove_resource::<NetConfig>();
+}
@@ -0,0 +1,417 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+
+use super::protocol::{MAX_INPUTS_PER_PACKET, NetInput, NetMessage};
+use crate::game::input::PlayerId;
+
+// 롤백 대상 상태. 입력 두 개로 한 틱 진행하고, 비교용 체크섬을 낸다
+pub trait RollbackState: Clone {
+    fn step(&mut self, inputs: [NetInput; 2]);
+    fn checksum(&self) -> u64;
+}
+
+// 상대 입력 없이 예측으로 앞서 나갈 수 있는 최대 틱. 넘으면 상대를 기다림
+pub const MAX_PREDICTION: u32 = 8;
+// 체크섬을 비교하는 간격(틱)
+const CHECKSUM_INTERVAL: u32 = 30;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SessionStats {
+    pub rollbacks: u32,       // 지금까지 되감은 횟수
+    pub last_rollback: u32,   // 마지막으로 다시 시뮬레이션한 틱 수
+    pub stalled: bool,        // 상대 입력을 기다리느라 멈춘 상태
+    pub remote_confirmed: u32, // 상대 입력이 확정된 틱 수
+}
+
+pub struct RollbackSession<S: RollbackState> {
+    local: usize,
+    input_delay: u32,
+    frame: u32, // 다음에 진행할 틱
+    state: S,
+    saved: VecDeque<(u32, S)>, // 틱 진행 직전 상태
+    local_inputs: Vec<NetInput>,
+    remote_inputs: Vec<Option<NetInput>>,
+    used_remote: Vec<NetInput>, // 실제로 시뮬레이션에 쓴 상대 입력 (예측 포함)
+    remote_confirmed: u32,      // 이 틱 이전의 상대 입력은 모두 확정
+    remote_ack: u32,            // 상대가 받은 로컬 입력 수
+    rollback_from: Option<u32>,
+    local_checksums: HashMap<u32, u64>,
+    remote_checksums: HashMap<u32, u64>,
+    last_checksum_frame: u32,
+    outbox: Vec<NetMessage>,
+    desync: Option<u32>,
+    stats: SessionStats,
+}
+
+impl<S: RollbackState> RollbackSession<S> {
+    pub fn new(local: PlayerId, state: S, input_delay: u32) -> Self {
+        Self {
+            local: local.index(),
+            input_delay,
+            frame: 0,
+            state,
+            saved: VecDeque::new(),
+            // 입력 지연만큼 앞의 틱은 빈 입력. 상대 것도 패킷으로 받아 확정한다
+            local_inputs: vec![NetInput::default(); input_delay as usize],
+            remote_inputs: Vec::new(),
+            used_remote: Vec::new(),
+            remote_confirmed: 0,
+            remote_ack: 0,
+            rollback_from: None,
+            local_checksums: HashMap::new(),
+            remote_checksums: HashMap::new(),
+            last_checksum_frame: 0,
+            outbox: Vec::new(),
+            desync: None,
+            stats: SessionStats::default(),
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    pub fn stats(&self) -> SessionStats {
+        self.stats
+    }
+
+    // 체크섬이 처음 어긋난 틱
+    pub fn desync(&self) -> Option<u32> {
+        self.desync
+    }
+
+    // 이번 틱의 로컬 입력. 지연만큼 뒤의 틱에 적용된다
+    pub fn add_local_input(&mut self, input: NetInput) {
+        // 멈춰 있는 동안 입력이 계속 쌓이지 않도록 지연 범위까지만
+        if self.local_inputs.len() <= (self.frame + self.input_delay) as usize {
+            self.local_inputs.push(input);
+        }
+    }
+
+    pub fn receive(&mut self, message: NetMessage) {
+        match message {
+            NetMessage::Input {
+                start_frame,
+                ack,
+                inputs,
+            } => {
+                self.remote_ack = self.remote_ack.max(ack);
+                let limit = self.receive_limit();
+                for (offset, input) in inputs.into_iter().enumerate() {
+                    match start_frame.checked_add(offset as u32) {
+                        Some(frame) if frame < limit => self.confirm_remote(frame, input),
+                        _ => {
+                            trace!("Dropped remote inputs from frame {} (limit {})", start_frame, limit);
+                            break;
+                        }
+                    }
+                }
+            }
+            NetMessage::Checksum { frame, value } => {
+                if frame >= self.receive_limit() {
+                    trace!("Dropped remote checksum for frame {}", frame);
+                    return;
+                }
+                self.remote_checksums.insert(frame, value);
+                self.compare_checksum(frame);
+            }
+        }
+    }
+
+    // 상대가 정상적으로 보낼 수 있는 틱의 끝. 그 너머는 잘못되었거나 위조된 패킷이므로 버린다
+    // (상대는 예측 한도까지 앞설 수 있고, 한 패킷에 입력을 최대 MAX_INPUTS_PER_PACKET개 담는다)
+    fn receive_limit(&self) -> u32 {
+        self.frame.saturating_add(MAX_PREDICTION + MAX_INPUTS_PER_PACKET as u32)
+    }
+
+    fn confirm_remote(&mut self, frame: u32, input: NetInput) {
+        let index = frame as usize;
+        if self.remote_inputs.len() <= index {
+            self.remote_inputs.resize(index + 1, None);
+        }
+        if self.remote_inputs[index].is_some() {
+            return;
+        }
+        self.remote_inputs[index] = Some(input);
+        // 이미 예측으로 진행한 틱인데 예측이 틀렸으면 그 틱부터 다시 계산
+        if let Some(&used) = self.used_remote.get(index)
+            && used != input
+        {
+            self.rollback_from = Some(self.rollback_from.map_or(frame, |from| from.min(frame)));
+        }
+        while let Some(Some(_)) = self.remote_inputs.get(self.remote_confirmed as usize) {
+            self.remote_confirmed += 1;
+        }
+    }
+
+    // 한 틱 진행. 상대를 기다려야 하면 false
+    pub fn advance(&mut self) -> bool {
+        if let Some(from) = self.rollback_from.take() {
+            self.rollback(from);
+        }
+        self.record_checksums();
+
+        let has_local = (self.frame as usize) < self.local_inputs.len();
+        let too_far_ahead = self.frame >= self.remote_confirmed + MAX_PREDICTION;
+        self.stats.stalled = !has_local || too_far_ahead;
+        self.stats.remote_confirmed = self.remote_confirmed;
+        if self.stats.stalled {
+            return false;
+        }
+        self.step_frame();
+        true
+    }
+
+    fn step_frame(&mut self) {
+        let frame = self.frame;
+        self.saved.push_back((frame, self.state.clone()));
+        // 되감을 수 있는 가장 이른 틱(확정 경계) 이전 상태는 버림
+        while self.saved.len() > (MAX_PREDICTION + 2) as usize {
+            self.saved.pop_front();
+        }
+
+        let remote = self.remote_input(frame);
+        let index = frame as usize;
+        if self.used_remote.len() <= index {
+            self.used_remote.resize(index + 1, NetInput::default());
+        }
+        self.used_remote[index] = remote;
+
+        let mut inputs = [NetInput::default(); 2];
+        inputs[self.local] = self.local_inputs[index];
+        inputs[1 - self.local] = remote;
+        self.state.step(inputs);
+        self.frame += 1;
+    }
+
+    // 확정된 입력이 없으면 마지막 확정 입력을 반복한다고 예측
+    fn remote_input(&self, frame: u32) -> NetInput {
+        if let Some(Some(input)) = self.remote_inputs.get(frame as usize) {
+            return *input;
+        }
+        self.remote_confirmed
+            .checked_sub(1)
+            .and_then(|last| self.remote_inputs[last as usize])
+            .unwrap_or_default()
+    }
+
+    fn rollback(&mut self, from: u32) {
+        let Some(position) = self.saved.iter().position(|(frame, _)| *frame == from) else {
+            // 예측 한도 안에서는 항상 저장되어 있어야 함
+            error!("Rollback to frame {} failed: state not saved", from);
+            return;
+        };
+        let target = self.frame;
+        self.state = self.saved[position].1.clone();
+        self.saved.truncate(position);
+        self.frame = from;
+        while self.frame < target {
+            self.step_frame();
+        }
+        self.stats.rollbacks += 1;
+        self.stats.last_rollback = target - from;
+        trace!("Rolled back {} frames from {}", target - from, from);
+    }
+
+    // 양쪽 입력이 확정된 틱의 상태는 더 이상 바뀌지 않으므로 체크섬을 기록해 보냄
+    fn record_checksums(&mut self) {
+        for (frame, state) in &self.saved {
+            let frame = *frame;
+            if frame <= self.last_checksum_frame || frame > self.remote_confirmed || frame % CHECKSUM_INTERVAL != 0 {
+                continue;
+            }
+            let value = state.checksum();
+            self.local_checksums.insert(frame, value);
+            self.outbox.push(NetMessage::Checksum { frame, value });
+            self.last_checksum_frame = frame;
+        }
+        let frames: Vec<u32> = self.local_checksums.keys().copied().collect();
+        for frame in frames {
+            self.compare_checksum(frame);
+        }
+    }
+
+    fn compare_checksum(&mut self, frame: u32) {
+        let (Some(local), Some(remote)) = (self.local_checksums.get(&frame), self.remote_checksums.get(&frame)) else {
+            return;
+        };
+        if local != remote && self.desync.is_none() {
+            error!("Desync detected at frame {}: local {:016x} != remote {:016x}", frame, local, remote);
+            self.desync = Some(frame);
+        }
+        // 비교가 끝난 기록은 정리
+        self.local_checksums.remove(&frame);
+        self.remote_checksums.remove(&frame);
+    }
+
+    // 이번 틱에 보낼 메시지. 상대가 받지 못한 로컬 입력을 모두 다시 보냄
+    pub fn drain_outgoing(&mut self) -> Vec<NetMessage> {
+        let start = (self.remote_ack as usize).min(self.local_inputs.len());
+        let end = self.local_inputs.len().min(start + MAX_INPUTS_PER_PACKET);
+        let mut messages = std::mem::take(&mut self.outbox);
+        messages.push(NetMessage::Input {
+            start_frame: start as u32,
+            ack: self.remote_confirmed,
+            inputs: self.local_inputs[start..end].to_vec(),
+        });
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 틱마다 받은 입력을 그대로 쌓는 상태. bias가 다르면 체크섬도 달라진다
+    #[derive(Clone, Default)]
+    struct History {
+        inputs: Vec<[NetInput; 2]>,
+        bias: u64,
+    }
+
+    impl RollbackState for History {
+        fn step(&mut self, inputs: [NetInput; 2]) {
+            self.inputs.push(inputs);
+        }
+
+        fn checksum(&self) -> u64 {
+            self.inputs
+                .iter()
+                .fold(self.bias, |hash, [a, b]| hash.wrapping_mul(31).wrapping_add((a.0 as u64) << 8 | b.0 as u64))
+        }
+    }
+
+    const A: NetInput = NetInput(NetInput::UP);
+    const B: NetInput = NetInput(NetInput::DOWN);
+
+    fn remote_inputs(start_frame: u32, inputs: Vec<NetInput>) -> NetMessage {
+        NetMessage::Input {
+            start_frame,
+            ack: 0,
+            inputs,
+        }
+    }
+
+    fn deliver(from: &mut RollbackSession<History>, to: &mut RollbackSession<History>) {
+        for message in from.drain_outgoing() {
+            to.receive(message);
+        }
+    }
+
+    #[test]
+    fn predicts_remote_input_and_rolls_back_on_misprediction() {
+        let mut session = RollbackSession::new(PlayerId::One, History::default(), 0);
+        session.add_local_input(NetInput::default());
+        session.receive(remote_inputs(0, vec![A]));
+        assert!(session.advance());
+
+        // 상대 입력 없이 진행하면 마지막 확정 입력(A)을 반복한다고 예측
+        for _ in 0..3 {
+            session.add_local_input(NetInput::default());
+            assert!(session.advance());
+        }
+        assert!(session.state().inputs[1..].iter().all(|inputs| inputs[1] == A));
+        assert_eq!(session.stats().rollbacks, 0);
+
+        // 실제로는 B였으면 틀린 틱부터 다시 계산
+        session.receive(remote_inputs(1, vec![B, B, B]));
+        session.add_local_input(NetInput::default());
+        assert!(session.advance());
+        let remote: Vec<NetInput> = session.state().inputs.iter().map(|inputs| inputs[1]).collect();
+        assert_eq!(remote, vec![A, B, B, B, B]);
+        assert_eq!(session.stats().rollbacks, 1);
+        assert_eq!(session.stats().last_rollback, 3);
+    }
+
+    #[test]
+    fn correct_prediction_does_not_roll_back() {
+        let mut session = RollbackSession::new(PlayerId::One, History::default(), 0);
+        session.add_local_input(NetInput::default());
+        session.receive(remote_inputs(0, vec![A]));
+        assert!(session.advance());
+        session.add_local_input(NetInput::default());
+        assert!(session.advance());
+
+        session.receive(remote_inputs(1, vec![A]));
+        session.add_local_input(NetInput::default());
+        assert!(session.advance());
+        assert_eq!(session.stats().rollbacks, 0);
+    }
+
+    #[test]
+    fn stalls_when_prediction_runs_too_far_ahead() {
+        let mut session = RollbackSession::new(PlayerId::One, History::default(), 0);
+        for _ in 0..MAX_PREDICTION {
+            session.add_local_input(NetInput::default());
+            assert!(session.advance());
+        }
+        session.add_local_input(NetInput::default());
+        assert!(!session.advance());
+        assert!(session.stats().stalled);
+        assert_eq!(session.frame(), MAX_PREDICTION);
+
+        // 상대 입력이 도착하면 다시 진행
+        session.receive(remote_inputs(0, vec![A]));
+        assert!(session.advance());
+        assert!(!session.stats().stalled);
+        assert_eq!(session.frame(), MAX_PREDICTION + 1);
+    }
+
+    #[test]
+    fn drops_inputs_outside_the_receive_window() {
+        let mut session = RollbackSession::new(PlayerId::One, History::default(), 0);
+        // 틱 번호가 넘치는 패킷과 한참 앞선 패킷은 버림
+        session.receive(remote_inputs(u32::MAX - 1, vec![A, A, A]));
+        session.receive(remote_inputs(1_000_000_000, vec![A]));
+        session.receive(NetMessage::Checksum {
+            frame: u32::MAX,
+            value: 0,
+        });
+        assert!(session.remote_inputs.is_empty());
+        assert!(session.remote_checksums.is_empty());
+
+        // 창 안의 입력은 그대로 받음
+        session.receive(remote_inputs(0, vec![A]));
+        session.add_local_input(NetInput::default());
+        assert!(session.advance());
+        assert_eq!(session.stats().remote_confirmed, 1);
+        assert_eq!(session.state().inputs[0][1], A);
+    }
+
+    // 두 세션이 매 틱 메시지를 주고받으며 진행
+    fn run_pair(a: &mut RollbackSession<History>, b: &mut RollbackSession<History>, frames: u32) {
+        for frame in 0..frames {
+            let input = NetInput((frame % 4) as u8);
+            a.add_local_input(input);
+            b.add_local_input(input);
+            deliver(a, b);
+            deliver(b, a);
+            a.advance();
+            b.advance();
+        }
+    }
+
+    #[test]
+    fn matching_checksums_stay_in_sync() {
+        let mut a = RollbackSession::new(PlayerId::One, History::default(), 2);
+        let mut b = RollbackSession::new(PlayerId::Two, History::default(), 2);
+        run_pair(&mut a, &mut b, CHECKSUM_INTERVAL * 3);
+        assert!(a.frame() > CHECKSUM_INTERVAL * 2);
+        assert_eq!(a.desync(), None);
+        assert_eq!(b.desync(), None);
+    }
+
+    #[test]
+    fn checksum_mismatch_reports_desync() {
+        let mut a = RollbackSession::new(PlayerId::One, History::default(), 2);
+        let mut b = RollbackSession::new(PlayerId::Two, History { bias: 1, ..Default::default() }, 2);
+        run_pair(&mut a, &mut b, CHECKSUM_INTERVAL * 2);
+        assert_eq!(a.desync(), Some(CHECKSUM_INTERVAL));
+        assert_eq!(b.desync(), Some(CHECKSUM_INTERVAL));
+    }
+}
@@ -0,0 +1,299 @@
+use bevy::prelude::*;
+
+use super::protocol::NetInput;
+use super::session::RollbackState;
+
+// 넷플레이 대전용 결정적 시뮬레이션.
+// 양쪽 PC가 같은 입력으로 같은 결과를 내도록 좌표는 서브픽셀 정수, 난수 없이 틱 수로만 진행한다.
+//
+// 캠페인/서바이벌(InGame 씬)과는 별개의 간이 대전 모드다. InGame 시스템은 가변 프레임 시간과
+// f32 좌표, 난수로 진행하고 상태가 ECS 곳곳에 흩어져 있어 두 PC에서 같은 결과를 보장할 수 없고
+// 되감기도 어렵다. 롤백 계층(RollbackSession)은 RollbackState만 요구하므로, InGame 로직을
+// 결정적인 고정 틱 상태로 옮기면 그대로 얹을 수 있다.
+
+pub const TICK_RATE: u32 = 60;
+pub const SUBPIXEL: i32 = 16; // 1px = 16 단위
+
+const HALF_W: i32 = 640 * SUBPIXEL;
+const HALF_H: i32 = 360 * SUBPIXEL;
+const SHIP_MARGIN: i32 = 60 * SUBPIXEL;
+const SHIP_SPEED: i32 = 320 * SUBPIXEL / TICK_RATE as i32;
+const SHIP_FOCUS_SPEED: i32 = 140 * SUBPIXEL / TICK_RATE as i32;
+const SHIP_HP: u8 = 3;
+const SHIP_RADIUS: i32 = 20 * SUBPIXEL;
+const INVULNERABLE_TICKS: u16 = 150;
+const RESPAWN_TICKS: u16 = 90;
+const FIRE_INTERVAL: u8 = 8;
+const SHOT_SPEED: i32 = 600 * SUBPIXEL / TICK_RATE as i32;
+const SHOT_TTL: u16 = 120;
+const SHOT_RADIUS: i32 = 12 * SUBPIXEL;
+const ENEMY_RADIUS: i32 = 28 * SUBPIXEL;
+const ENEMY_HP: u8 = 3;
+const ENEMY_ROW_HEIGHT: i32 = 100 * SUBPIXEL;
+const SPAWN_INTERVAL: u32 = 48;
+const KILL_SCORE: u32 = 100;
+
+// 대각선 이동 보정 (1/√2 ≈ 181/256)
+const DIAGONAL_NUM: i32 = 181;
+const DIAGONAL_DEN: i32 = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ship {
+    pub pos: IVec2,
+    pub hp: u8,
+    pub invulnerable: u16, // 남은 무적 틱
+    pub respawn: u16,      // 0이 아니면 격추되어 재등장 대기 중
+    pub fire_cooldown: u8,
+    pub score: u32,
+}
+
+impl Ship {
+    fn new(index: usize) -> Self {
+        Self {
+            pos: Self::start(index),
+            hp: SHIP_HP,
+            invulnerable: INVULNERABLE_TICKS,
+            respawn: 0,
+            fire_cooldown: 0,
+            score: 0,
+        }
+    }
+
+    fn start(index: usize) -> IVec2 {
+        let y = if index == 0 { 90 } else { -90 };
+        IVec2::new(-HALF_W + SHIP_MARGIN, y * SUBPIXEL)
+    }
+
+    pub fn alive(&self) -> bool {
+        self.respawn == 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shot {
+    pub id: u32,
+    pub pos: IVec2,
+    pub owner: u8,
+    pub ttl: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetEnemy {
+    pub id: u32,
+    pub pos: IVec2,
+    pub speed: i32,
+    pub hp: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetSim {
+    pub frame: u32,
+    pub ships: [Ship; 2],
+    pub shots: Vec<Shot>,
+    pub enemies: Vec<NetEnemy>,
+    pub next_id: u32,
+    pub columns: u32,
+}
+
+impl Default for NetSim {
+    fn default() -> Self {
+        Self {
+            frame: 0,
+            ships: [Ship::new(0), Ship::new(1)],
+            shots: Vec::new(),
+            enemies: Vec::new(),
+            next_id: 0,
+            columns: 0,
+        }
+    }
+}
+
+// 서브픽셀 좌표를 월드 좌표로
+pub fn to_world(pos: IVec2) -> Vec2 {
+    pos.as_vec2() / SUBPIXEL as f32
+}
+
+fn overlaps(a: IVec2, b: IVec2, radius: i32) -> bool {
+    let d = (a - b).as_i64vec2();
+    d.length_squared() <= radius as i64 * radius as i64
+}
+
+impl NetSim {
+    fn next_id(&mut self) -> u32 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn update_ships(&mut self, inputs: [NetInput; 2]) {
+        for (index, input) in inputs.into_iter().enumerate() {
+            let ship = &mut self.ships[index];
+            if ship.respawn > 0 {
+                ship.respawn -= 1;
+                if ship.respawn == 0 {
+                    ship.pos = Ship::start(index);
+                    ship.hp = SHIP_HP;
+                    ship.invulnerable = INVULNERABLE_TICKS;
+                }
+                continue;
+            }
+            ship.invulnerable = ship.invulnerable.saturating_sub(1);
+
+            let dir = input.direction();
+            let speed = if input.pressed(NetInput::FOCUS) {
+                SHIP_FOCUS_SPEED
+            } else {
+                SHIP_SPEED
+            };
+            let mut step = dir * speed;
+            if dir.x != 0 && dir.y != 0 {
+                step = step * DIAGONAL_NUM / DIAGONAL_DEN;
+            }
+            let min = IVec2::new(-HALF_W + SHIP_MARGIN, -HALF_H + SHIP_MARGIN);
+            let max = IVec2::new(HALF_W - SHIP_MARGIN, HALF_H - SHIP_MARGIN);
+            ship.pos = (ship.pos + step).clamp(min, max);
+
+            if ship.fire_cooldown == 0 {
+                ship.fire_cooldown = FIRE_INTERVAL;
+                let pos = ship.pos + IVec2::new(30 * SUBPIXEL, 0);
+                let id = self.next_id();
+                self.shots.push(Shot {
+                    id,
+                    pos,
+                    owner: index as u8,
+                    ttl: SHOT_TTL,
+                });
+            } else {
+                ship.fire_cooldown -= 1;
+            }
+        }
+    }
+
+    fn spawn_column(&mut self) {
+        let rows = (2 * HALF_H / ENEMY_ROW_HEIGHT).max(1);
+        // 열마다 속도를 조금씩 바꿔 단조로움을 줄임
+        let speed = (300 + (self.columns % 3) as i32 * 40) * SUBPIXEL / TICK_RATE as i32;
+        for row in 0..rows {
+            let id = self.next_id();
+            self.enemies.push(NetEnemy {
+                id,
+                pos: IVec2::new(HALF_W + 60 * SUBPIXEL, -HALF_H + ENEMY_ROW_HEIGHT * row + ENEMY_ROW_HEIGHT / 2),
+                speed,
+                hp: ENEMY_HP,
+            });
+        }
+        self.columns += 1;
+    }
+
+    fn resolve_hits(&mut self) {
+        // 총알-적
+        let mut i = 0;
+        while i < self.shots.len() {
+            let shot = &self.shots[i];
+            let hit = self
+                .enemies
+                .iter()
+                .position(|enemy| overlaps(shot.pos, enemy.pos, SHOT_RADIUS + ENEMY_RADIUS));
+            let Some(enemy_index) = hit else {
+                i += 1;
+                continue;
+            };
+            let owner = shot.owner as usize;
+            self.shots.remove(i);
+            let enemy = &mut self.enemies[enemy_index];
+            enemy.hp -= 1;
+            if enemy.hp == 0 {
+                self.enemies.remove(enemy_index);
+                self.ships[owner].score += KILL_SCORE;
+            }
+        }
+
+        // 적-기체
+        for ship in &mut self.ships {
+            if !ship.alive() || ship.invulnerable > 0 {
+                continue;
+            }
+            let hit = self
+                .enemies
+                .iter()
+                .position(|enemy| overlaps(ship.pos, enemy.pos, SHIP_RADIUS + ENEMY_RADIUS));
+            if let Some(enemy_index) = hit {
+                self.enemies.remove(enemy_index);
+                ship.hp -= 1;
+                ship.invulnerable = INVULNERABLE_TICKS / 2;
+                if ship.hp == 0 {
+                    ship.respawn = RESPAWN_TICKS;
+                }
+            }
+        }
+    }
+}
+
+impl RollbackState for NetSim {
+    fn step(&mut self, inputs: [NetInput; 2]) {
+        self.update_ships(inputs);
+
+        for shot in &mut self.shots {
+            shot.pos.x += SHOT_SPEED;
+            shot.ttl -= 1;
+        }
+        self.shots.retain(|shot| shot.ttl > 0 && shot.pos.x < HALF_W + 80 * SUBPIXEL);
+
+        if self.frame.is_multiple_of(SPAWN_INTERVAL) {
+            self.spawn_column();
+        }
+        for enemy in &mut self.enemies {
+            enemy.pos.x -= enemy.speed;
+        }
+        self.enemies.retain(|enemy| enemy.pos.x > -HALF_W - 60 * SUBPIXEL);
+
+        self.resolve_hits();
+        self.frame += 1;
+    }
+
+    // FNV-1a. 플랫폼과 무관하게 같은 값이 나오도록 필드를 직접 섞음
+    fn checksum(&self) -> u64 {
+        let mut hash = Fnv::default();
+        hash.write_u32(self.frame);
+        for ship in &self.ships {
+            hash.write_ivec2(ship.pos);
+            hash.write_u32(ship.hp as u32);
+            hash.write_u32(ship.invulnerable as u32);
+            hash.write_u32(ship.respawn as u32);
+            hash.write_u32(ship.fire_cooldown as u32);
+            hash.write_u32(ship.score);
+        }
+        for shot in &self.shots {
+            hash.write_u32(shot.id);
+            hash.write_ivec2(shot.pos);
+        }
+        for enemy in &self.enemies {
+            hash.write_u32(enemy.id);
+            hash.write_ivec2(enemy.pos);
+            hash.write_u32(enemy.hp as u32);
+        }
+        hash.write_u32(self.next_id);
+        hash.0
+    }
+}
+
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv {
+    fn write_u32(&mut self, value: u32) {
+        for byte in value.to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_ivec2(&mut self, value: IVec2) {
+        self.write_u32(value.x as u32);
+        self.write_u32(value.y as u32);
+    }
+}
@@ -0,0 +1,53 @@
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+
+use bevy::prelude::*;
+
+use super::protocol::NetMessage;
+
+// 논블로킹 UDP 소켓. 손실된 패킷은 세션이 입력을 다시 보내 메운다
+pub struct UdpTransport {
+    socket: UdpSocket,
+    peer: SocketAddr,
+}
+
+impl UdpTransport {
+    pub fn bind(bind: SocketAddr, peer: SocketAddr) -> io::Result<Self> {
+        let socket = UdpSocket::bind(bind)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self { socket, peer })
+    }
+
+    pub fn send(&self, message: &NetMessage) {
+        if let Err(err) = self.socket.send_to(&message.encode(), self.peer) {
+            // 상대가 아직 실행 전이면 거부될 수 있음
+            if err.kind() != io::ErrorKind::WouldBlock {
+                trace!("UDP send failed: {}", err);
+            }
+        }
+    }
+
+    // 지금까지 도착한 메시지를 모두 읽음. 상대 주소가 아닌 패킷은 버림
+    pub fn receive(&self) -> Vec<NetMessage> {
+        let mut messages = Vec::new();
+        let mut buf = [0u8; 512];
+        loop {
+            match self.socket.recv_from(&mut buf) {
+                Ok((len, from)) if from == self.peer => {
+                    if let Some(message) = NetMessage::decode(&buf[..len]) {
+                        messages.push(message);
+                    }
+                }
+                Ok(_) => {}
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                // Windows에서는 상대 포트가 닫혀 있으면 ConnectionReset이 옴
+                Err(err) if err.kind() == io::ErrorKind::ConnectionReset => continue,
+                Err(err) => {
+                    warn!("UDP receive failed: {}", err);
+                    break;
+                }
+            }
+        }
+        messages
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::window::PrimaryWindow;
+
+// 게임 로직이 사용하는 고정 가상 해상도. 창 크기와 무관하게 경계가 유지된다
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Playfield {
+    pub size: Vec2,
+}
+
+impl Default for Playfield {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(1280.0, 720.0),
+        }
+    }
+}
+
+impl Playfield {
+    pub fn half_size(&self) -> Vec2 {
+        self.size * 0.5
+    }
+
+    // 경계를 margin만큼 넓힌 영역에 포함되는지 (음수면 안쪽으로 좁힘)
+    pub fn contains(&self, position: Vec2, margin: f32) -> bool {
+        let half = self.half_size() + Vec2::splat(margin);
+        position.x.abs() <= half.x && position.y.abs() <= half.y
+    }
+
+    // 가상 해상도 전체를 비추는 카메라 투영
+    pub fn projection(&self) -> OrthographicProjection {
+        OrthographicProjection {
+            scaling_mode: ScalingMode::Fixed {
+                width: self.size.x,
+                height: self.size.y,
+            },
+            ..OrthographicProjection::default_2d()
+        }
+    }
+}
+
+// 플레이필드를 비추는 카메라. 창 비율이 달라도 레터박스로 비율을 유지
+#[derive(Component)]
+#[require(Camera2d)]
+pub struct PlayfieldCamera;
+
+pub fn playfield_camera(playfield: &Playfield) -> impl Bundle {
+    (
+        PlayfieldCamera,
+        playfield.projection(),
+        Camera {
+            clear_color: ClearColorConfig::Custom(Color::BLACK),
+            ..Default::default()
+        },
+    )
+}
+
+// 창 크기에 맞춰 가상 해상도 비율을 유지하는 뷰포트를 계산 (남는 영역은 레터박스)
+pub fn fit_playfield_viewport(
+    playfield: Res<Playfield>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Camera, With<PlayfieldCamera>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let physical = window.physical_size();
+    if physical.x == 0 || physical.y == 0 {
+        return;
+    }
+
+    let window_size = physical.as_vec2();
+    let scale = (window_size.x / playfield.size.x).min(window_size.y / playfield.size.y);
+    let size = (playfield.size * scale).round().max(Vec2::ONE);
+    let position = ((window_size - size) * 0.5).floor();
+    let viewport = Viewport {
+        physical_position: position.as_uvec2(),
+        physical_size: size.as_uvec2(),
+        ..Default::default()
+    };
+
+    for mut camera in &mut cameras {
+        let unchanged = camera.viewport.as_ref().is_some_and(|current| {
+            current.physical_position == viewport.physical_position
+                && current.physical_size == viewport.physical_size
+        });
+        if !unchanged {
+            camera.viewport = Some(viewport.clone());
+        }
+    }
+}
+
+pub struct PlayfieldPlugin;
+
+impl Plugin for PlayfieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Playfield>()
+            .add_systems(PostUpdate, fit_playfield_viewport.before(bevy::render::camera::CameraUpdateSystem));
+    }
+}
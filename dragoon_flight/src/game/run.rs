@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// 이번 판의 플레이 방식
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub enum RunMode {
+    #[default]
+    Classic, // 끝없이 같은 패턴이 이어지는 기본 모드
+    Survival, // 시간이 지날수록 어려워지는 생존 모드
+    Campaign { stage: usize },
+    Practice { start: f32 }, // 생존 곡선의 start초 지점부터 연습
+}
+
+impl RunMode {
+    // 생존 곡선을 따라 난이도가 오르는 모드
+    pub fn follows_curve(self) -> bool {
+        matches!(self, RunMode::Survival | RunMode::Practice { .. })
+    }
+}
+
+// 한 판 동안의 기록. 스테이지 결과 화면에 표시된다
+#[derive(Resource, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RunStats {
+    pub kills: u32,
+    pub shots_fired: u32,
+    pub shots_hit: u32,
+    pub damage_taken: u32,
+    pub elapsed: f32, // 초
+}
+
+impl RunStats {
+    // 명중률 (0.0 ~ 1.0)
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            0.0
+        } else {
+            self.shots_hit as f32 / self.shots_fired as f32
+        }
+    }
+}
+
+// 스테이지 클리어 결과. 결과 화면이 읽는다
+#[derive(Resource, Debug, Clone)]
+pub struct StageResult {
+    pub stage: usize,
+    pub stats: RunStats,
+    pub score: u32,
+    pub unlocked_next: bool,
+}
+
+// 생존 모드 종료 기록. 게임 오버 화면이 읽는다
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SurvivalResult {
+    pub time: f32,
+    pub score: u32,
+    pub rank: Option<usize>, // 기록판 순위 (0부터). 순위 밖이면 None
+}
@@ -0,0 +1,122 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use bevy::log::{info, warn};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::game::build_info::BuildInfo;
+
+const APP_DIR: &str = "dragoon_flight";
+
+// 세이브 파일 공통 형식. 호환성 확인을 위해 파일을 쓴 빌드 정보를 함께 남긴다
+#[derive(Serialize)]
+struct SaveFileOut<'a, T> {
+    build: BuildInfo,
+    data: &'a T,
+}
+
+#[derive(Deserialize)]
+struct SaveFileIn<T> {
+    build: BuildInfo,
+    data: T,
+}
+
+// 빌드 정보를 담는 봉투의 키. 이 키가 없으면 빌드 정보를 기록하기 전의 파일(데이터만 있음)
+const BUILD_KEY: &str = "build";
+
+// 세이브 파일을 둘 디렉터리. 플랫폼 기본 데이터 경로를 쓰고, 찾지 못하면 현재 디렉터리
+pub fn save_dir() -> PathBuf {
+    let base = std::env::var_os("APPDATA")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("XDG_DATA_HOME").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join(APP_DIR)
+}
+
+pub fn write_json<T: Serialize>(file_name: &str, value: &T) -> io::Result<PathBuf> {
+    let dir = save_dir();
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(file_name);
+    let file = SaveFileOut { build: BuildInfo::current(), data: value };
+    let json = serde_json::to_string_pretty(&file).map_err(io::Error::other)?;
+    // 쓰는 도중 종료되어도 기존 파일이 깨지지 않도록 임시 파일에 쓴 뒤 교체
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, &path)?;
+    Ok(path)
+}
+
+// 파일이 없으면 Ok(None)
+pub fn read_json<T: DeserializeOwned>(file_name: &str) -> io::Result<Option<T>> {
+    let path = save_dir().join(file_name);
+    match fs::read_to_string(&path) {
+        Ok(json) => parse_save_file(file_name, &json).map(Some).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// 봉투가 있는 형식을 먼저 읽고, 봉투가 있는데 실패하면 그 에러를 그대로 돌려준다
+// (예전 형식으로 다시 읽으면 기본값으로 채워진 엉뚱한 데이터가 될 수 있음).
+// 다른 버전이 쓴 파일도 읽을 수 있는 한 그대로 쓰되, 문제 추적을 위해 로그를 남긴다
+fn parse_save_file<T: DeserializeOwned>(file_name: &str, json: &str) -> serde_json::Result<T> {
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let err = match serde_json::from_value::<SaveFileIn<T>>(value.clone()) {
+        Ok(SaveFileIn { build, data }) => {
+            let current = BuildInfo::current();
+            if !current.is_compatible(&build) {
+                warn!("{} was written by {}, running {}", file_name, build, current);
+            }
+            return Ok(data);
+        }
+        Err(err) => err,
+    };
+    if value.get(BUILD_KEY).is_some() {
+        return Err(err);
+    }
+    let data = serde_json::from_value(value)?;
+    info!("{} has no build info; it will be upgraded on next save", file_name);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    struct Progress {
+        unlocked: u32,
+        best: Vec<u32>,
+    }
+
+    impl Default for Progress {
+        fn default() -> Self {
+            Self { unlocked: 1, best: Vec::new() }
+        }
+    }
+
+    #[test]
+    fn legacy_bare_json_still_loads() {
+        let loaded: Progress = parse_save_file("progress.json", r#"{ "unlocked": 3, "best": [1200, 900] }"#).unwrap();
+        assert_eq!(loaded, Progress { unlocked: 3, best: vec![1200, 900] });
+    }
+
+    #[test]
+    fn versioned_file_loads() {
+        let progress = Progress { unlocked: 2, best: vec![500] };
+        let json = serde_json::to_string(&SaveFileOut { build: BuildInfo::current(), data: &progress }).unwrap();
+        let loaded: Progress = parse_save_file("progress.json", &json).unwrap();
+        assert_eq!(loaded, progress);
+    }
+
+    #[test]
+    fn broken_versioned_file_reports_its_error() {
+        let mut value = serde_json::to_value(SaveFileOut { build: BuildInfo::current(), data: &Progress::default() }).unwrap();
+        value["data"]["unlocked"] = serde_json::json!("three");
+        let err = parse_save_file::<Progress>("progress.json", &value.to_string()).unwrap_err();
+        assert!(err.to_string().contains("invalid type"), "{}", err);
+    }
+}
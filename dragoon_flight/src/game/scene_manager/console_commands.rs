@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use dragoon_engine::scene::{SceneRegistry, SceneStack, SceneStatus};
+use crate::game::console::app_extensions::{AppConsoleExtensions, ConsoleResult};
+
+pub fn add_console_commands(app: &mut App) {
+    app.add_console_command(
+        "scene",
+        "scene <name> | push <name> | pop | replace <name> | stack",
+        &[
+            "Main", "StageSelect", "PracticeSelect", "InGame", "StageClear", "GameOver", "Pause", "NetPlay", "push", "pop",
+            "replace", "stack",
+        ],
+        scene_command,
+    );
+}
+
+// 씬 강제 전환 및 씬 스택 조작
+fn scene_command(
+    In(args): In<Vec<String>>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+    mut stack: ResMut<SceneStack>,
+    registry: Res<SceneRegistry>,
+) -> ConsoleResult {
+    let parse = |name: Option<&String>| {
+        let name = name.ok_or("usage: scene <push|replace> <name>")?;
+        registry.find(name).ok_or_else(|| {
+            let known: Vec<&str> = registry.ids().collect();
+            format!("unknown scene '{}' (known: {})", name, known.join(", "))
+        })
+    };
+    // 콘솔은 씬의 전환 선언과 무관하게 어디로든 이동하므로 검사를 건너뜀
+    match args.first().map(String::as_str) {
+        None => Err("usage: scene <name>".to_string()),
+        Some("stack") => Ok(format!("stack {:?}", stack.scenes())),
+        Some("pop") => {
+            stack.pop();
+            Ok("scene pop".to_string())
+        }
+        Some("push") => {
+            let scene = parse(args.get(1))?;
+            stack.skip_transition_check();
+            stack.push(scene);
+            Ok(format!("scene push {:?}", scene))
+        }
+        Some("replace") => {
+            let scene = parse(args.get(1))?;
+            stack.skip_transition_check();
+            stack.replace(scene);
+            Ok(format!("scene replace {:?}", scene))
+        }
+        Some(_) => {
+            let scene = parse(args.first())?;
+            stack.skip_transition_check();
+            next_state.set(scene);
+            Ok(format!("scene -> {:?}", scene))
+        }
+    }
+}
@@ -1,9 +1,8 @@
 // 공개 모듈
 pub mod plugin;
+pub mod scene_ids;
+pub mod score;
 
 // 내부 모듈
-mod app_extensions;
-mod scene_states;
-mod scene_traits;
+mod console_commands;
 mod scenes;
-pub mod score;
@@ -1,22 +1,62 @@
-use crate::game::entity::anime::FrameCache;
+use crate::game::background::parallax::{ParallaxConfig, ScrollPacing};
+use crate::game::campaign::{progress::CampaignProgress, stages::Campaign};
+use crate::game::difficulty::Difficulty;
+use crate::game::events::GameplayEvent;
+use crate::game::run::RunMode;
+use crate::game::settings::Settings;
+use crate::game::survival::{curve::SurvivalCurve, scores::SurvivalScores};
 
-use super::app_extensions::AppSceneExtensions;
-use super::scene_states::SceneStatus;
+use super::console_commands;
+use super::scene_ids;
 use super::scenes::{
-    game_over_scene::GameOverScene, ingame_scene::InGameScene, main_scene::MainScene,
+    game_over_scene::GameOverScene, ingame_scene::{self, InGameScene}, main_scene::MainScene,
+    pause_scene::PauseScene, practice_select_scene::PracticeSelectScene,
+    stage_clear_scene::StageClearScene, stage_select_scene::StageSelectScene,
 };
 use super::score::Score;
 use bevy::prelude::*;
+use dragoon_engine::animation::AnimationSet;
+use dragoon_engine::collision::CollisionSet;
+use dragoon_engine::kinematics::KinematicsSet;
+use dragoon_engine::scene::{AppSceneExtensions, SceneSet};
 
 pub struct ScenesPlugin;
 
 impl Plugin for ScenesPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_state(SceneStatus::Main)
-            .add_scene(MainScene {})
+        // 씬 관리 기반(SceneManagerPlugin)은 main에서 추가
+        app.add_scene(MainScene {})
             .add_scene(InGameScene {})
-            .add_scene(GameOverScene);
+            .add_scene(StageSelectScene)
+            .add_scene(PracticeSelectScene)
+            .add_scene(StageClearScene)
+            .add_scene(GameOverScene)
+            .add_scene(PauseScene)
+            // 엔진 가속도/애니메이션/콜라이더 갱신은 인게임에서만 (일시정지 중엔 멈춤).
+            // 가속도는 게임플레이 이동 전에, 애니메이션/콜라이더는 게임플레이 갱신 뒤에
+            .configure_sets(
+                Update,
+                (
+                    KinematicsSet.before(SceneSet::Update),
+                    (AnimationSet, CollisionSet).chain().after(SceneSet::Update),
+                )
+                    .run_if(in_state(scene_ids::IN_GAME)),
+            );
 
-        app.init_resource::<Score>().init_resource::<FrameCache>();
+        app.init_resource::<Score>()
+            .init_resource::<Settings>()
+            .init_resource::<Difficulty>()
+            .init_resource::<RunMode>()
+            .init_resource::<Campaign>()
+            .init_resource::<CampaignProgress>()
+            .init_resource::<SurvivalCurve>()
+            .init_resource::<SurvivalScores>()
+            .init_resource::<ParallaxConfig>()
+            .init_resource::<ScrollPacing>()
+            .add_event::<GameplayEvent>();
+
+        console_commands::add_console_commands(app);
+        ingame_scene::add_console_commands(app);
     }
+
 }
@@ -0,0 +1,10 @@
+use dragoon_engine::scene::SceneStatus;
+
+// 게임 기본 씬 ID (넷플레이 씬은 net::scene::NET_PLAY)
+pub const MAIN: SceneStatus = SceneStatus::new("Main");
+pub const STAGE_SELECT: SceneStatus = SceneStatus::new("StageSelect");
+pub const PRACTICE_SELECT: SceneStatus = SceneStatus::new("PracticeSelect");
+pub const IN_GAME: SceneStatus = SceneStatus::new("InGame");
+pub const STAGE_CLEAR: SceneStatus = SceneStatus::new("StageClear");
+pub const GAME_OVER: SceneStatus = SceneStatus::new("GameOver");
+pub const PAUSE: SceneStatus = SceneStatus::new("Pause"); // InGame 위에 쌓이는 일시정지 오버레이
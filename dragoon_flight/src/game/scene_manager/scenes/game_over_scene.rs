@@ -1,45 +1,103 @@
 use bevy::{ecs::schedule::SystemConfigs, prelude::*};
-use super::super::{scene_states::SceneStatus, scene_traits::IScene};
+use super::super::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStatus};
+use crate::game::fonts::registry::FontRole;
+use crate::game::locale::text::Localized;
+use crate::game::run::{RunMode, SurvivalResult};
+use crate::game::survival::scores::{self, SurvivalScores};
 
 pub struct GameOverScene;
 
 impl IScene for GameOverScene {
-    fn state(&self) -> SceneStatus { SceneStatus::GameOver }
+    fn state(&self) -> SceneStatus { scene_ids::GAME_OVER }
 
     fn system_on_enter(&self) -> SystemConfigs { setup_game_over.into_configs() }
 
     fn system_on_update(&self) -> SystemConfigs { game_over_interaction.into_configs() }
 
-    fn system_on_exit(&self) -> SystemConfigs { despawn_screen::<OnGameOverScreen>.into_configs() }
+    fn transitions(&self) -> Vec<SceneStatus> { vec![scene_ids::MAIN] }
 }
 
-#[derive(Component)]
-struct OnGameOverScreen;
+fn setup_game_over(
+    mut commands: Commands,
+    run_mode: Res<RunMode>,
+    result: Option<Res<SurvivalResult>>,
+    board: Res<SurvivalScores>,
+) {
+    commands.spawn(Camera2d);
 
-fn setup_game_over(mut commands: Commands) {
-    commands.spawn((Camera2d, OnGameOverScreen));
+    // 생존 모드면 이번 기록과 기록판을 함께 표시
+    let survival = match (*run_mode, result) {
+        (RunMode::Survival, Some(result)) => Some(result),
+        _ => None,
+    };
 
     commands
         .spawn((
-            OnGameOverScreen,
-            Node { justify_content: JustifyContent::Center, align_items: AlignItems::Center, width: Val::Percent(100.0), height: Val::Percent(100.0), ..Default::default() },
+            Node { justify_content: JustifyContent::Center, align_items: AlignItems::Center, flex_direction: FlexDirection::Column, row_gap: Val::Px(24.0), width: Val::Percent(100.0), height: Val::Percent(100.0), ..Default::default() },
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("Game Over\nPress Enter to return"),
+                Localized::new("game-over"),
+                FontRole::Title,
                 TextFont { font_size: 48.0, ..Default::default() },
                 TextColor(Color::srgb(0.9, 0.2, 0.2)),
                 TextLayout::new_with_justify(JustifyText::Center),
             ));
+            if let Some(result) = survival {
+                spawn_survival_summary(parent, &result, &board);
+            }
         });
 }
 
-fn game_over_interaction(keys: Res<ButtonInput<KeyCode>>, mut next: ResMut<NextState<SceneStatus>>) {
-    if keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space) {
-        next.set(SceneStatus::Main);
+// 이번 기록 두 줄, 기록판 제목, 기록판 순. 기록판 숫자 표는 번역하지 않는다
+fn spawn_survival_summary(parent: &mut ChildBuilder, result: &SurvivalResult, board: &SurvivalScores) {
+    let record = match result.rank {
+        Some(rank) => Localized::new("survival-new-record").with("rank", rank + 1),
+        None => Localized::new("survival-no-record"),
+    };
+    let mut table = String::new();
+    for (i, entry) in board.entries.iter().enumerate() {
+        let marker = if result.rank == Some(i) { ">" } else { " " };
+        table.push_str(&format!(
+            "{}{:>2}. {:>7}  {:06}\n",
+            marker,
+            i + 1,
+            scores::format_time(entry.time),
+            entry.score
+        ));
     }
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            let font = TextFont { font_size: 24.0, ..Default::default() };
+            let color = TextColor(Color::srgb(0.9, 0.9, 0.9));
+            parent.spawn((
+                Localized::new("survival-result")
+                    .with("time", scores::format_time(result.time))
+                    .with("score", format!("{:06}", result.score)),
+                font.clone(),
+                color,
+            ));
+            parent.spawn((record, font.clone(), color));
+            parent.spawn((
+                Localized::new("survival-records"),
+                font.clone(),
+                color,
+                Node { margin: UiRect::top(Val::Px(24.0)), ..Default::default() },
+            ));
+            parent.spawn((Text::new(table), font, color, TextLayout::new_with_justify(JustifyText::Center)));
+        });
 }
 
-fn despawn_screen<T: Component>(to_despawn: Query<Entity, With<T>>, mut commands: Commands) {
-    for e in &to_despawn { commands.entity(e).despawn_recursive(); }
+fn game_over_interaction(keys: Res<ButtonInput<KeyCode>>, mut next: ResMut<NextState<SceneStatus>>) {
+    if keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space) {
+        next.set(scene_ids::MAIN);
+    }
 }
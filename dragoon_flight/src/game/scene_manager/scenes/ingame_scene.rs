@@ -1,16 +1,45 @@
-use super::super::scene_states::SceneStatus;
-use super::super::scene_traits::*;
+use super::super::scene_ids;
+use super::super::score::Score;
 use crate::game::entity::{
-    anime::{self, *},
-    entity_properties::{self, AutoSizeCollider, Collider, CollisionCheck, Velocity2D},
+    movement::{self, PlayerMovement},
     spawner::*,
 };
-use bevy::{ecs::schedule::*, prelude::Or, prelude::*};
+use crate::game::background::parallax::{self, ParallaxConfig, ScrollPacing};
+use crate::game::audio::music::{MusicCue, MusicState};
+use crate::game::camera::effects::CameraShake;
+use crate::game::campaign::{
+    progress::CampaignProgress,
+    stages::{BossDef, Campaign, WaveDef},
+};
+use crate::game::difficulty::{self, Difficulty};
+use crate::game::events::GameplayEvent;
+use crate::game::fonts::registry::FontRole;
+use crate::game::input::{MAX_PLAYERS, PlayerId};
+use crate::game::locale::text::Localized;
+use crate::game::playfield::{self, Playfield};
+use crate::game::run::{RunMode, RunStats, StageResult, SurvivalResult};
+use crate::game::survival::{
+    curve::{EnemyArchetype, SurvivalCurve},
+    scores::{self, SurvivalScores},
+};
+use crate::game::settings::Settings;
+use crate::game::vfx::{
+    particles::Particle,
+    prefabs,
+};
+use bevy::{ecs::schedule::*, prelude::*, sprite::Anchor};
+use dragoon_engine::animation::anime::*;
+use dragoon_engine::collision::{AutoSizeCollider, Collider, CollisionCheck};
+use dragoon_engine::kinematics::Velocity2D;
+use dragoon_engine::scene::{IScene, SceneResources, SceneStack, SceneStatus};
+use serde::{Deserialize, Serialize};
 
-#[derive(Component)]
-struct OnInGameScreen;
+mod console_commands;
+mod debug_info;
+mod practice;
+mod snapshot;
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 struct Health {
     current: u32,
     max: u32,
@@ -22,38 +51,258 @@ struct HealthBar {
     height: f32,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 struct Player {
     fire_timer: Timer,
+    weapon: Weapon,
+}
+
+// 플레이어별 무기. 1P는 빠른 단발, 2P는 느린 3갈래
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Weapon {
+    Rapid,
+    Spread,
+}
+
+impl Weapon {
+    fn for_player(player: PlayerId) -> Self {
+        match player {
+            PlayerId::One => Weapon::Rapid,
+            PlayerId::Two => Weapon::Spread,
+        }
+    }
+
+    fn interval(self) -> f32 {
+        match self {
+            Weapon::Rapid => 0.125,
+            Weapon::Spread => 0.2,
+        }
+    }
+
+    // 발사 각도(라디안). 0이 정면
+    fn angles(self) -> &'static [f32] {
+        match self {
+            Weapon::Rapid => &[0.0],
+            Weapon::Spread => &[-0.2, 0.0, 0.2],
+        }
+    }
 }
 
 #[derive(Component)]
 struct Enemy {}
 
+// 플레이어를 조준해 쏘는 적
+#[derive(Component, Clone, Serialize, Deserialize)]
+struct EnemyGun {
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct EnemyBullet;
+
+// 위아래로 흔들리며 이동하는 적
+#[derive(Component, Clone, Serialize, Deserialize)]
+struct Weave {
+    base_y: f32,
+    phase: f32,
+    amplitude: f32,
+}
+
+// 생존 모드 경과 시간 표시
+#[derive(Component)]
+struct SurvivalTimeText;
+
+// 스테이지 마지막의 보스. 진입 후 위아래로 움직이며 부채꼴 탄을 쏨
+#[derive(Component, Clone, Serialize, Deserialize)]
+struct Boss {
+    target_x: f32,
+    phase: f32,
+    spread: u32,
+    fire_timer: Timer,
+}
+
 #[derive(Component)]
+struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthFill;
+
+// 캠페인 스테이지 진행 상태
+#[derive(Resource, Clone, Serialize, Deserialize)]
+struct StageRun {
+    stage: usize,
+    wave: usize,
+    pause: Timer,
+    phase: StagePhase,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum StagePhase {
+    Waves,
+    Boss,
+    Cleared(Timer), // 보스 격파 후 결과 화면까지 대기
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
 struct Bullet {
     life: Timer,
+    owner: PlayerId, // 처치 점수를 받을 플레이어
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 struct DyingFade {
     timer: Timer,
 }
 
-#[derive(Resource)]
+// 남은 목숨 (현재 기체 포함). 공유 목숨이면 remaining[0] 하나를 모두가 함께 씀
+#[derive(Resource, Clone, Serialize, Deserialize)]
+struct Lives {
+    remaining: [u32; MAX_PLAYERS],
+    players: usize,
+    shared: bool,
+}
+
+impl Lives {
+    fn new(players: usize, shared: bool) -> Self {
+        let mut remaining = [0; MAX_PLAYERS];
+        if shared {
+            remaining[0] = PLAYER_LIVES * players as u32;
+        } else {
+            remaining[..players].fill(PLAYER_LIVES);
+        }
+        Self {
+            remaining,
+            players,
+            shared,
+        }
+    }
+
+    fn of(&self, player: PlayerId) -> u32 {
+        if self.shared {
+            self.remaining[0]
+        } else {
+            self.remaining[player.index()]
+        }
+    }
+
+    // 기체 하나를 잃음. 다시 나올 기체가 남았으면 true
+    // others_in_play: 다른 플레이어가 쓰고 있거나 재등장 대기 중인 기체 수 (공유 목숨에서만 의미 있음)
+    fn lose(&mut self, player: PlayerId, others_in_play: u32) -> bool {
+        if self.shared {
+            self.remaining[0] = self.remaining[0].saturating_sub(1);
+            self.remaining[0] > others_in_play
+        } else {
+            let remaining = &mut self.remaining[player.index()];
+            *remaining = remaining.saturating_sub(1);
+            *remaining > 0
+        }
+    }
+
+    fn refill(&mut self) {
+        *self = Self::new(self.players, self.shared);
+    }
+}
+
+// 진입 시 현재 설정의 인원수/공유 여부로 시작
+impl FromWorld for Lives {
+    fn from_world(world: &mut World) -> Self {
+        let gameplay = world.resource::<Settings>().gameplay;
+        Self::new(gameplay.coop.player_count(), gameplay.shared_lives)
+    }
+}
+
+// 사망 후 재등장까지 대기 중인 플레이어
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+struct RespawnQueue(Vec<(PlayerId, Timer)>);
+
+// 재등장 직후와 피격 직후 무적. 깜빡임으로 표시
+#[derive(Component, Clone, Serialize, Deserialize)]
+struct Invulnerable {
+    timer: Timer,
+    blink: Timer,
+}
+
+// 화면 왼쪽 밖에서 시작 위치까지 진입 중 (입력 무시)
+#[derive(Component, Clone, Serialize, Deserialize)]
+struct RespawnEntry {
+    target: Vec2,
+}
+
+// 목숨을 모두 잃었을 때의 이어하기 카운트다운
+#[derive(Resource, Clone, Serialize, Deserialize)]
+struct ContinuePrompt {
+    countdown: Timer,
+}
+
+#[derive(Component)]
+struct ContinueScreen;
+
+#[derive(Component)]
+struct ContinueCountdownText;
+
+#[derive(Component)]
+struct LivesHud(PlayerId);
+
+// None이면 합계 점수
+#[derive(Component)]
+struct ScoreText(Option<PlayerId>);
+
+const PLAYER_MARGIN: f32 = 60.0;
+const PLAYER_LIVES: u32 = 3;
+const ENEMY_SCORE: u32 = 100;
+const ENEMY_HEALTH: u32 = 5;
+const ENEMY_BULLET_SPEED: f32 = 260.0;
+const BOSS_SCORE: u32 = 5000;
+const CONTINUE_SECONDS: f32 = 10.0;
+// 총알-적 원 충돌 반경. 적 반경은 스케일에 비례
+const BULLET_HIT_RADIUS: f32 = 12.0;
+const ENEMY_HIT_RADIUS: f32 = 28.0;
+// 재등장 지점 주변에서 제거할 적의 반경
+const RESPAWN_CLEAR_RADIUS: f32 = 260.0;
+// 피격 직후 무적 시간. 보스처럼 사라지지 않는 적과 닿아 있어도 매 프레임 맞지 않도록
+const HURT_INVULNERABLE_SECS: f32 = 1.0;
+
+#[derive(Resource, Clone, Serialize, Deserialize)]
 struct EnemySpawner {
     row_height: f32,
     col_spacing: f32,
     speed: f32,
+    columns: u32,           // 지금까지 내보낸 열 수 (사격 적 배치에 사용)
+    gunner_every: u32,      // n칸에 하나꼴로 사격 적 (0이면 없음)
+    remaining: Option<u32>, // 이번 웨이브에 남은 열 수. None이면 무한
+    health_scale: f32,      // 적 체력 배율 (생존 모드 곡선이 조정)
+    mix: Vec<(EnemyArchetype, u32)>, // 등장할 적 종류와 가중치. 비어 있으면 기본 적만
     margin: f32,
     timer: Timer,
 }
 
+pub use console_commands::add_console_commands;
+use console_commands::GodMode;
+
+impl Default for EnemySpawner {
+    fn default() -> Self {
+        let col_spacing = 240.0;
+        let speed = 360.0;
+        Self {
+            row_height: 100.0,
+            col_spacing,
+            speed,
+            columns: 0,
+            gunner_every: 4,
+            remaining: None,
+            health_scale: 1.0,
+            mix: Vec::new(),
+            margin: 60.0,
+            timer: Timer::from_seconds(col_spacing / speed, TimerMode::Repeating),
+        }
+    }
+}
+
 pub struct InGameScene {}
 
 impl IScene for InGameScene {
     fn state(&self) -> SceneStatus {
-        SceneStatus::InGame
+        scene_ids::IN_GAME
     }
 
     fn system_on_enter(&self) -> SystemConfigs {
@@ -63,41 +312,99 @@ impl IScene for InGameScene {
 
     fn system_on_update(&self) -> SystemConfigs {
         (
-            player_move_system,
-            player_auto_fire_system,
+            (
+                movement::player_movement_system,
+                player_auto_fire_system,
+                player_respawn_system,
+                respawn_entry_system,
+                invulnerability_system,
+            ),
             bullet_update_system,
+            (
+                enemy_fire_system,
+                enemy_bullet_update_system,
+                enemy_bullet_hit_system,
+                difficulty::adaptive_difficulty_system,
+            ),
             enemy_update_system,
             enemy_spawn_system,
             enemy_despawn_offscreen_system,
             bullet_enemy_hit_system,
             enemy_fadeout_system,
             player_enemy_collision_system,
-            health_bar_update_system,
-            // 애니메이션 프레임 갱신 후 콜라이더 자동 설정 적용
-            anime::animate_sprite,
-            entity_properties::auto_size_colliders_system,
+            continue_prompt_system,
+            pause_system,
+            (stage_progress_system, boss_system, run_clock_system),
+            (
+                survival_ramp_system,
+                practice::practice_system,
+                debug_info::hitbox_gizmo_system,
+                debug_info::debug_info_system,
+                snapshot::snapshot_hotkey_system.run_if(|| cfg!(debug_assertions)),
+            ),
+            parallax::scroll_parallax_system,
         )
             .into_configs()
     }
 
-    fn system_on_exit(&self) -> SystemConfigs {
-        on_exit.into_configs()
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::PAUSE, scene_ids::GAME_OVER, scene_ids::STAGE_CLEAR, scene_ids::PRACTICE_SELECT]
+    }
+
+    fn system_on_ui(&self) -> Option<SystemConfigs> {
+        Some((health_bar_update_system, hud_update_system, boss_health_bar_system).into_configs())
+    }
+
+    fn system_on_exit(&self) -> Option<SystemConfigs> {
+        Some(on_exit.into_configs())
+    }
+
+    // 일시정지 오버레이 뒤에서 정지된 화면을 계속 보여줌
+    fn render_while_paused(&self) -> bool {
+        true
+    }
+
+    fn resources(&self, resources: &mut SceneResources) {
+        resources
+            .add::<EnemySpawner>()
+            .add::<Lives>()
+            .add::<RespawnQueue>()
+            .add::<RunStats>();
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn on_start(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    playfield: Res<Playfield>,
     mut frame_cache: ResMut<FrameCache>,
+    parallax_config: Res<ParallaxConfig>,
+    mut pacing: ResMut<ScrollPacing>,
+    settings: Res<Settings>,
+    mut score: ResMut<Score>,
+    mut difficulty: ResMut<Difficulty>,
+    run_mode: Res<RunMode>,
+    campaign: Res<Campaign>,
+    mut music: ResMut<MusicState>,
+    curve: Res<SurvivalCurve>,
+    mut spawner: ResMut<EnemySpawner>,
+    mut stats: ResMut<RunStats>,
+    lives: Res<Lives>,
 ) {
-    // 인게임 카메라 생성
-    commands.spawn((Camera2d, OnInGameScreen));
-    // 창 크기 계산 및 플레이어 X 앵커
-    let window = windows.single();
-    let half_w = window.width() / 2.0;
-    let player_margin = 60.0;
-    let player_x = -half_w + player_margin;
+    difficulty.reset_pressure();
+
+    // 캠페인이면 스테이지 설정(배경/음악/웨이브)을 사용
+    let stage = match *run_mode {
+        RunMode::Campaign { stage } => campaign.stage(stage).map(|def| (stage, def)),
+        RunMode::Classic | RunMode::Survival | RunMode::Practice { .. } => None,
+    };
+    let background = stage.map_or(&*parallax_config, |(_, def)| &def.background);
+
+    // 인게임 카메라 생성 (화면 흔들림 적용 대상)
+    commands.spawn((playfield::playfield_camera(&playfield), CameraShake::default()));
+    // 배경 레이어
+    parallax::spawn_parallax_layers(&mut commands, &asset_server, background, playfield.size);
 
     let player_frames = load_frames(
         &asset_server,
@@ -108,94 +415,508 @@ fn on_start(
         ],
     );
 
+    let players = settings.gameplay.coop.player_count();
+    spawn_hud(&mut commands, player_frames[0].clone(), &lives);
+    for player in PlayerId::ALL.into_iter().take(players) {
+        spawn_player(&mut commands, player_frames.clone(), &playfield, &settings, player, false);
+    }
+    frame_cache.map.insert("player".into(), player_frames);
+
+    let enemy_frames = load_frames(
+        &asset_server,
+        vec![
+            "anime/enemy_1.png".to_string(),
+            "anime/enemy_2.png".to_string(),
+            "anime/enemy_3.png".to_string(),
+        ],
+    );
+    frame_cache.map.insert("enemy".into(), enemy_frames);
+
+    let bullet_frames = load_frames(
+        &asset_server,
+        vec![
+            "anime/bullet_1.png".to_string(),
+            "anime/bullet_2.png".to_string(),
+            "anime/bullet_3.png".to_string(),
+        ],
+    );
+    frame_cache.map.insert("bullet".into(), bullet_frames);
+
+    // 배경은 적 이동 속도의 2/3 정도로 흘려 진행감을 맞춤
+    pacing.speed = spawner.speed * 0.66;
+    if let Some((index, def)) = stage {
+        pacing.speed = def.scroll_speed;
+        music.request(def.music);
+        if let Some(first) = def.waves.first() {
+            spawner.apply_wave(first);
+        }
+        commands.insert_resource(StageRun {
+            stage: index,
+            wave: 0,
+            pause: Timer::from_seconds(0.0, TimerMode::Once),
+            phase: StagePhase::Waves,
+        });
+        info!("Stage {} ({}) started", index + 1, def.name_key);
+    }
+    if run_mode.follows_curve() {
+        // 생존 모드는 사격 적도 곡선의 종류 구성으로 등장
+        spawner.gunner_every = 0;
+        spawner.apply_curve(&curve, 0.0);
+        if let RunMode::Practice { start } = *run_mode {
+            // 연습 모드: 고른 시각까지 스포너를 미리 진행시켜 같은 배치가 나오도록
+            stats.elapsed = start;
+            spawner.fast_forward(&curve, &difficulty, stats.elapsed);
+            commands.insert_resource(practice::PracticeRun::new());
+            practice::spawn_practice_hud(&mut commands);
+            info!("Practice from {}", scores::format_time(stats.elapsed));
+        }
+        pacing.speed = spawner.speed * 0.66;
+        commands.spawn((
+            SurvivalTimeText,
+            Text::new(scores::format_time(stats.elapsed)),
+            FontRole::Hud,
+            TextFont { font_size: 28.0, ..Default::default() },
+            TextColor(Color::srgb(0.95, 0.95, 0.95)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(14.0),
+                left: Val::Percent(47.0),
+                ..Default::default()
+            },
+        ));
+    }
+    *score = Score::default();
+}
+
+// 플레이어 시작 위치. 2인이면 위아래로 나눠 배치
+fn player_start(player: PlayerId, settings: &Settings, playfield: &Playfield) -> Vec2 {
+    let x = -playfield.half_size().x + PLAYER_MARGIN;
+    if settings.gameplay.coop.player_count() == 1 {
+        return Vec2::new(x, 0.0);
+    }
+    match player {
+        PlayerId::One => Vec2::new(x, 90.0),
+        PlayerId::Two => Vec2::new(x, -90.0),
+    }
+}
+
+// 플레이어 생성. entering이면 화면 왼쪽 밖에서 진입하며 무적 상태로 시작
+fn spawn_player(
+    commands: &mut Commands,
+    frames: Vec<Handle<Image>>,
+    playfield: &Playfield,
+    settings: &Settings,
+    player: PlayerId,
+    entering: bool,
+) -> Entity {
+    let half_size = playfield.half_size();
+    let start = player_start(player, settings, playfield);
+    let spawn_at = if entering {
+        Vec2::new(-half_size.x - PLAYER_MARGIN, start.y)
+    } else {
+        start
+    };
+
     let player_entity = spawn_entity(
-        &mut commands,
+        commands,
         Animation {
-            frames: player_frames,
+            frames,
             timer: Timer::from_seconds(0.5, TimerMode::Repeating),
             current_frame_index: 0,
             state: AnimationPlaybackState::Playing,
         },
-        Vec3::new(player_x, 0.0, 0.0),
+        spawn_at.extend(0.0),
         Vec3::new(1.0, 1.0, 1.0),
     );
+    let weapon = Weapon::for_player(player);
+    let coop = settings.gameplay.coop.player_count() > 1;
     commands.entity(player_entity)
         .insert((
             Player {
-                fire_timer: Timer::from_seconds(0.125, TimerMode::Repeating),
+                fire_timer: Timer::from_seconds(weapon.interval(), TimerMode::Repeating),
+                weapon,
             },
+            player,
             Collider {
                 offset: Vec3::ZERO,
                 scale: Vec3::ONE,
             },
             AutoSizeCollider { multiplier: Vec2::new(0.5, 0.5), padding: Vec2::ZERO },
             Health { current: 3, max: 3 },
+        ))
+        .with_children(|parent| {
+            parent.spawn(prefabs::engine_trail());
+            // 기체 아래 체력바 (배경 + 전경)
+            let bar = HealthBar { max_width: 48.0, height: 5.0 };
+            parent.spawn((
+                Sprite::from_color(Color::srgba(0.1, 0.1, 0.1, 0.7), Vec2::new(bar.max_width, bar.height)),
+                Transform::from_xyz(0.0, -36.0, 0.3),
+            ));
+            parent.spawn((
+                Sprite {
+                    color: player.color(),
+                    custom_size: Some(Vec2::new(bar.max_width, bar.height)),
+                    anchor: Anchor::CenterLeft,
+                    ..Default::default()
+                },
+                Transform::from_xyz(-bar.max_width / 2.0, -36.0, 0.4),
+                bar,
+            ));
+            if coop {
+                parent.spawn((
+                    Text2d::new(player.label()),
+                    TextFont { font_size: 16.0, ..Default::default() },
+                    TextColor(player.color()),
+                    Transform::from_xyz(0.0, 40.0, 0.4),
+                ));
+            }
+        });
+
+    if entering {
+        commands.entity(player_entity).insert((
+            RespawnEntry { target: start },
+            Invulnerable {
+                timer: Timer::from_seconds(2.5, TimerMode::Once),
+                blink: Timer::from_seconds(0.1, TimerMode::Repeating),
+            },
         ));
+    } else {
+        commands.entity(player_entity).insert(PlayerMovement::from_preset(
+            settings.gameplay.movement,
+            half_size,
+            PLAYER_MARGIN,
+        ));
+    }
+    player_entity
+}
 
-    let enemy_frames = load_frames(
-        &asset_server,
-        vec![
-            "anime/enemy_1.png".to_string(),
-            "anime/enemy_2.png".to_string(),
-            "anime/enemy_3.png".to_string(),
-        ],
-    );
-    frame_cache.map.insert("enemy".into(), enemy_frames);
+// 상단 HUD: 왼쪽은 남은 목숨 아이콘, 오른쪽은 점수. 2인이면 플레이어별로 표시
+fn spawn_hud(commands: &mut Commands, life_icon: Handle<Image>, lives: &Lives) {
+    let coop = lives.players > 1;
+    // 공유 목숨이면 목숨 표시는 하나만
+    let life_groups = if lives.shared { 1 } else { lives.players };
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    for player in PlayerId::ALL.into_iter().take(life_groups) {
+                        parent
+                            .spawn(Node {
+                                column_gap: Val::Px(8.0),
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            })
+                            .with_children(|parent| {
+                                if coop && !lives.shared {
+                                    parent.spawn((
+                                        Text::new(player.label()),
+                                        FontRole::Hud,
+                                        TextFont { font_size: 22.0, ..Default::default() },
+                                        TextColor(player.color()),
+                                    ));
+                                }
+                                parent
+                                    .spawn((
+                                        LivesHud(player),
+                                        Node {
+                                            column_gap: Val::Px(6.0),
+                                            ..Default::default()
+                                        },
+                                    ))
+                                    .with_children(|parent| {
+                                        for _ in 0..lives.of(player) {
+                                            parent.spawn(life_icon_node(life_icon.clone()));
+                                        }
+                                    });
+                            });
+                    }
+                });
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::End,
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        ScoreText(None),
+                        score_label(None, &Score::default()),
+                        FontRole::Hud,
+                        TextFont { font_size: 28.0, ..Default::default() },
+                        TextColor(Color::srgb(0.95, 0.95, 0.95)),
+                    ));
+                    if coop {
+                        for player in PlayerId::ALL.into_iter().take(lives.players) {
+                            parent.spawn((
+                                ScoreText(Some(player)),
+                                score_label(Some(player), &Score::default()),
+                                FontRole::Hud,
+                                TextFont { font_size: 20.0, ..Default::default() },
+                                TextColor(player.color()),
+                            ));
+                        }
+                    }
+                });
+        });
+}
 
-    let bullet_frames = load_frames(
-        &asset_server,
-        vec![
-            "anime/bullet_1.png".to_string(),
-            "anime/bullet_2.png".to_string(),
-            "anime/bullet_3.png".to_string(),
-        ],
-    );
-    frame_cache.map.insert("bullet".into(), bullet_frames);
+fn score_label(player: Option<PlayerId>, score: &Score) -> Localized {
+    match player {
+        None => Localized::new("hud-score").with("score", format!("{:06}", score.value)),
+        Some(player) => Localized::new("hud-player-score")
+            .with("player", player.label())
+            .with("score", format!("{:06}", score.of(player))),
+    }
+}
 
-    let row_h: f32 = 100.0;
-    let col_spacing = 240.0;
-    let speed = 360.0;
-    commands.insert_resource(EnemySpawner {
-        row_height: row_h,
-        col_spacing,
-        speed,
-        margin: 60.0,
-        timer: Timer::from_seconds(col_spacing / speed, TimerMode::Repeating),
+fn life_icon_node(image: Handle<Image>) -> impl Bundle {
+    (
+        ImageNode::new(image),
+        Node {
+            width: Val::Px(32.0),
+            height: Val::Px(32.0),
+            ..Default::default()
+        },
+    )
+}
+
+// 목숨/점수 변화 시 HUD 갱신
+#[allow(clippy::too_many_arguments)]
+fn hud_update_system(
+    mut commands: Commands,
+    lives: Option<Res<Lives>>,
+    score: Res<Score>,
+    frame_cache: Res<FrameCache>,
+    huds: Query<(Entity, &LivesHud)>,
+    mut score_texts: Query<(&mut Localized, &ScoreText)>,
+    mut time_texts: Query<&mut Text, With<SurvivalTimeText>>,
+    stats: Res<RunStats>,
+) {
+    for mut text in &mut time_texts {
+        text.0 = scores::format_time(stats.elapsed);
+    }
+    if score.is_changed() {
+        for (mut localized, label) in &mut score_texts {
+            *localized = score_label(label.0, &score);
+        }
+    }
+
+    let Some(lives) = lives else {
+        return;
+    };
+    if !lives.is_changed() {
+        return;
+    }
+    let Some(icon) = frame_cache.map.get("player").and_then(|frames| frames.first()) else {
+        return;
+    };
+    for (hud, LivesHud(player)) in &huds {
+        commands.entity(hud).despawn_descendants().with_children(|parent| {
+            for _ in 0..lives.of(*player) {
+                parent.spawn(life_icon_node(icon.clone()));
+            }
+        });
+    }
+}
+
+// 사망 후 대기 시간이 지나면 플레이어를 다시 진입시키고 주변 적을 정리
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn player_respawn_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    queue: Option<ResMut<RespawnQueue>>,
+    frame_cache: Res<FrameCache>,
+    playfield: Res<Playfield>,
+    settings: Res<Settings>,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, Without<DyingFade>, Without<Boss>)>,
+    enemy_bullets: Query<(Entity, &Transform), With<EnemyBullet>>,
+    mut events: EventWriter<GameplayEvent>,
+) {
+    let Some(mut queue) = queue else {
+        return;
+    };
+    let mut ready = Vec::new();
+    queue.0.retain_mut(|(player, timer)| {
+        timer.tick(time.delta());
+        if timer.finished() {
+            ready.push(*player);
+        }
+        !timer.finished()
     });
+
+    for player in ready {
+        let frames = frame_cache.map.get("player").expect("Missing frames");
+        spawn_player(&mut commands, frames.clone(), &playfield, &settings, player, true);
+
+        let respawn_point = player_start(player, &settings, &playfield);
+        for (e, tf) in &enemies {
+            let position = tf.translation.truncate();
+            if position.distance(respawn_point) <= RESPAWN_CLEAR_RADIUS {
+                commands.entity(e).insert(DyingFade {
+                    timer: Timer::from_seconds(0.4, TimerMode::Once),
+                });
+                events.send(GameplayEvent::EnemyCleared { position });
+            }
+        }
+        for (e, tf) in &enemy_bullets {
+            if tf.translation.truncate().distance(respawn_point) <= RESPAWN_CLEAR_RADIUS {
+                commands.entity(e).despawn_recursive();
+            }
+        }
+        debug!("Player {} respawned", player.label());
+    }
 }
 
-// 플레이어 이동 입력 처리
-fn player_move_system(
+// 진입 완료 시 이동 제어를 넘겨줌
+fn respawn_entry_system(
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    mut q: Query<&mut Transform, With<Player>>,
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    settings: Res<Settings>,
+    mut q: Query<(Entity, &RespawnEntry, &mut Transform), With<Player>>,
 ) {
-    let window = windows.single();
-    let half_w = window.width() / 2.0;
-    let half_h = window.height() / 2.0;
-    let margin = 60.0;
-    let anchor_x = -half_w + margin;
+    let entry_speed = 420.0;
+    for (e, entry, mut tf) in &mut q {
+        let next = tf
+            .translation
+            .truncate()
+            .move_towards(entry.target, entry_speed * time.delta_secs());
+        tf.translation.x = next.x;
+        tf.translation.y = next.y;
+        if next == entry.target {
+            commands
+                .entity(e)
+                .remove::<RespawnEntry>()
+                .insert(PlayerMovement::from_preset(
+                    settings.gameplay.movement,
+                    playfield.half_size(),
+                    PLAYER_MARGIN,
+                ));
+        }
+    }
+}
 
-    if let Ok(mut tf) = q.get_single_mut() {
-        let mut dir = Vec2::ZERO;
-        // 좌우 이동 금지, 상하만 허용
-        if keys.any_pressed([KeyCode::ArrowUp, KeyCode::KeyW]) {
-            dir.y += 1.0;
+// 무적 시간 동안 깜빡이다가 끝나면 원래대로
+fn invulnerability_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut Invulnerable, &mut Sprite), Without<DyingFade>>,
+) {
+    for (e, mut invulnerable, mut sprite) in &mut q {
+        invulnerable.timer.tick(time.delta());
+        invulnerable.blink.tick(time.delta());
+        if invulnerable.timer.finished() {
+            sprite.color.set_alpha(1.0);
+            commands.entity(e).remove::<Invulnerable>();
+            continue;
         }
-        if keys.any_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) {
-            dir.y -= 1.0;
+        if invulnerable.blink.just_finished() {
+            let alpha = if sprite.color.alpha() < 1.0 { 1.0 } else { 0.3 };
+            sprite.color.set_alpha(alpha);
         }
-        if dir.length_squared() > 0.0 {
-            dir = dir.normalize();
+    }
+}
+
+fn spawn_continue_screen(commands: &mut Commands) {
+    commands
+        .spawn((
+            ContinueScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ContinueCountdownText,
+                continue_label(CONTINUE_SECONDS.ceil() as u32),
+                FontRole::Hud,
+                TextFont { font_size: 48.0, ..Default::default() },
+                TextColor(Color::srgb(0.95, 0.85, 0.3)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+fn continue_label(seconds: u32) -> Localized {
+    Localized::new("continue-prompt").with("seconds", seconds)
+}
+
+// 이어하기: Enter면 점수 초기화 후 목숨을 채워 모두 재등장, 시간 초과나 Esc면 게임 오버
+// P(연습/컨티뉴 중이 아니면 Esc도)로 일시정지 오버레이를 올림
+fn pause_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    prompt: Option<Res<ContinuePrompt>>,
+    practice: Option<Res<practice::PracticeRun>>,
+    mut stack: ResMut<SceneStack>,
+) {
+    let escape = keys.just_pressed(KeyCode::Escape) && prompt.is_none() && practice.is_none();
+    if keys.just_pressed(KeyCode::KeyP) || escape {
+        stack.push(scene_ids::PAUSE);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn continue_prompt_system(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    prompt: Option<ResMut<ContinuePrompt>>,
+    mut lives: ResMut<Lives>,
+    mut queue: ResMut<RespawnQueue>,
+    mut score: ResMut<Score>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+    screens: Query<Entity, With<ContinueScreen>>,
+    mut texts: Query<&mut Localized, With<ContinueCountdownText>>,
+) {
+    let Some(mut prompt) = prompt else {
+        return;
+    };
+    prompt.countdown.tick(time.delta());
+
+    if keys.just_pressed(KeyCode::Enter) {
+        *score = Score::default();
+        lives.refill();
+        commands.remove_resource::<ContinuePrompt>();
+        // 모든 플레이어를 다시 진입시킴
+        for player in PlayerId::ALL.into_iter().take(lives.players) {
+            queue.0.push((player, Timer::from_seconds(0.2, TimerMode::Once)));
         }
-        // 속도는 컴포넌트에서 읽음
-        let speed = 300.0; // 기본값 (Player 컴포넌트 값과 일치)
-        // X는 왼쪽 끝에 고정
-        tf.translation.x = anchor_x;
-        // Y 이동 및 화면 내 클램프
-        tf.translation.y += dir.y * speed * time.delta_secs();
-        tf.translation.y = tf.translation.y.clamp(-half_h + margin, half_h - margin);
+        for e in &screens {
+            commands.entity(e).despawn_recursive();
+        }
+        info!("Continue used");
+        return;
+    }
+    if keys.just_pressed(KeyCode::Escape) || prompt.countdown.finished() {
+        info!("No continue -> GameOver");
+        next_state.set(scene_ids::GAME_OVER);
+        return;
+    }
+
+    let seconds = prompt.countdown.remaining_secs().ceil() as u32;
+    for mut localized in &mut texts {
+        localized.set_if_neq(continue_label(seconds));
     }
 }
 
@@ -203,126 +924,268 @@ fn player_move_system(
 fn player_auto_fire_system(
     time: Res<Time>,
     frame_cache: ResMut<FrameCache>,
-    mut q_player: Query<(&Transform, &mut Player), (With<Player>, Without<DyingFade>)>,
+    mut q_player: Query<(&Transform, &mut Player, &PlayerId), Without<DyingFade>>,
     mut commands: Commands,
+    mut stats: ResMut<RunStats>,
+    mut events: EventWriter<GameplayEvent>,
 ) {
-    if let Ok((tf, mut player)) = q_player.get_single_mut() {
+    for (tf, mut player, owner) in &mut q_player {
         player.fire_timer.tick(time.delta());
-        if player.fire_timer.just_finished() {
-            let frames = frame_cache.map.get("bullet").expect("Missing frames");
-            let bullet_entity = spawn_entity(
+        if !player.fire_timer.just_finished() {
+            continue;
+        }
+        let frames = frame_cache.map.get("bullet").expect("Missing frames");
+        for &angle in player.weapon.angles() {
+            let velocity = Vec2::from_angle(angle) * 600.0;
+            spawn_player_bullet(
                 &mut commands,
-                Animation {
-                    frames: frames.clone(),
-                    timer: Timer::from_seconds(0.05, TimerMode::Repeating),
-                    current_frame_index: 0,
-                    state: AnimationPlaybackState::Playing,
-                },
+                frames.clone(),
                 tf.translation + Vec3::new(30.0, 0.0, 0.1),
-                Vec3::ONE,
+                velocity,
+                *owner,
             );
-            commands
-                .entity(bullet_entity)
-                .insert(Velocity2D { x: 600.0, y: 0.0 })
-                .insert(Bullet {
-                    life: Timer::from_seconds(2.0, TimerMode::Once),
-                });
-            info!("Bullet fired at x={:.1}, y={:.1}", tf.translation.x, tf.translation.y);
+            stats.shots_fired += 1;
         }
+        events.send(GameplayEvent::BulletFired {
+            position: tf.translation.truncate() + Vec2::new(30.0, 0.0),
+        });
+        trace!("Bullet fired by {} at x={:.1}, y={:.1}", owner.label(), tf.translation.x, tf.translation.y);
     }
 }
 
+fn spawn_player_bullet(
+    commands: &mut Commands,
+    frames: Vec<Handle<Image>>,
+    position: Vec3,
+    velocity: Vec2,
+    owner: PlayerId,
+) -> Entity {
+    let bullet_entity = spawn_entity(
+        commands,
+        Animation {
+            frames,
+            timer: Timer::from_seconds(0.05, TimerMode::Repeating),
+            current_frame_index: 0,
+            state: AnimationPlaybackState::Playing,
+        },
+        position,
+        Vec3::ONE,
+    );
+    commands
+        .entity(bullet_entity)
+        .insert(Velocity2D { x: velocity.x, y: velocity.y })
+        .insert(Bullet {
+            life: Timer::from_seconds(2.0, TimerMode::Once),
+            owner,
+        });
+    bullet_entity
+}
+
 // 총알 이동 및 생명 주기 처리
+#[allow(clippy::type_complexity)]
 fn bullet_update_system(
     time: Res<Time>,
     mut commands: Commands,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    playfield: Res<Playfield>,
     mut q: Query<(Entity, &mut Transform, &Velocity2D, &mut Bullet), (With<Bullet>, Without<DyingFade>)>,
 ) {
-    let window = windows.single();
-    let half_w = window.width() / 2.0;
-    let half_h = window.height() / 2.0;
     let margin = 80.0;
     for (e, mut tf, velocity, mut bullet) in &mut q {
         tf.translation.x += velocity.x * time.delta_secs();
         tf.translation.y += velocity.y * time.delta_secs();
         bullet.life.tick(time.delta());
-        let x = tf.translation.x;
-        let y = tf.translation.y;
-        if x > half_w + margin
-            || x < -half_w - margin
-            || y > half_h + margin
-            || y < -half_h - margin
-            || bullet.life.finished()
-        {
+        if !playfield.contains(tf.translation.truncate(), margin) || bullet.life.finished() {
             commands.entity(e).despawn_recursive();
         }
     }
 }
 
-// 기존 on_update와 on_exit 함수는 그대로 유지
-fn on_update() {}
-fn on_exit(
-    mut commands: Commands,
-    mut q: Query<Entity, Or<(With<Bullet>, With<Player>, With<Enemy>, With<DyingFade>, With<OnInGameScreen>)>>,
-) {
-    for e in &mut q {
-        commands.entity(e).despawn_recursive();
-    }
+// 엔티티는 SceneScoped로 정리되므로 진행 중에 넣은 리소스만 제거
+fn on_exit(mut commands: Commands) {
+    commands.remove_resource::<ContinuePrompt>();
+    commands.remove_resource::<StageRun>();
+    commands.remove_resource::<practice::PracticeRun>();
 }
 
 // 적 이동
+#[allow(clippy::type_complexity)]
 fn enemy_update_system(
     time: Res<Time>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    mut q: Query<(&mut Transform, &Velocity2D), With<Enemy>>,
+    mut q: Query<(&mut Transform, &Velocity2D, Option<&mut Weave>), (With<Enemy>, Without<Boss>)>,
 ) {
-    let window = windows.single();
-    let half_w = window.width() / 2.0;
-    let margin = 60.0;
-    for (mut tf, velocity) in &mut q {
+    for (mut tf, velocity, weave) in &mut q {
         tf.translation.x -= velocity.x * time.delta_secs();
+        if let Some(mut weave) = weave {
+            weave.phase += time.delta_secs() * 3.0;
+            tf.translation.y = weave.base_y + weave.phase.sin() * weave.amplitude;
+        }
         // 화면 밖으로 나간 적은 별도 시스템에서 despawn
-        let _ = half_w;
-        let _ = margin;
     }
 }
 
 // 플레이어-적 충돌 처리: 플레이어 체력 감소 및 사망 처리
+#[allow(clippy::type_complexity)]
 fn player_enemy_collision_system(
     mut commands: Commands,
-    mut players: Query<(Entity, &Transform, &Collider, &mut Health), (With<Player>, Without<DyingFade>)>,
-    enemies: Query<(Entity, &Transform, &Collider), (With<Enemy>, Without<DyingFade>)>,
+    mut players: Query<(Entity, &Transform, &Collider, &mut Health), (With<Player>, Without<DyingFade>, Without<Invulnerable>)>,
+    enemies: Query<(Entity, &Transform, &Collider, Has<Boss>), (With<Enemy>, Without<DyingFade>)>,
+    mut stats: ResMut<RunStats>,
+    mut events: EventWriter<GameplayEvent>,
+    practice: Option<Res<practice::PracticeRun>>,
+    god: Res<GodMode>,
 ) {
-    if let Ok((p_ent, p_tf, p_col, mut hp)) = players.get_single_mut() {
-        for (e_ent, e_tf, e_col) in &enemies {
+    for (p_ent, p_tf, p_col, mut hp) in &mut players {
+        for (e_ent, e_tf, e_col, is_boss) in &enemies {
             let p_collider = (p_tf, p_col);
             let e_collider = (e_tf, e_col);
             if p_collider.check_collision(&e_collider) {
                 let before = hp.current;
-                info!(
+                debug!(
                     "Player-Enemy HIT: hp {} -> {} (pending)",
                     before,
                     before.saturating_sub(1)
                 );
-                // 적과 충돌 시 적 제거 (이미 제거되었을 수 있으므로 존재 확인)
-                if let Some(mut ecmd) = commands.get_entity(e_ent) {
+                // 적과 충돌 시 적 제거 (보스는 유지, 이미 제거되었을 수 있으므로 존재 확인)
+                if !is_boss
+                    && let Some(ecmd) = commands.get_entity(e_ent)
+                {
                     ecmd.despawn_recursive();
                 }
-                
-                if hp.current > 0 {
-                    hp.current -= 1;
-                }
-                
-                // 체력이 0이 되면 페이드아웃 시작
-                if hp.current == 0 {
-                    if let Some(mut pcmd) = commands.get_entity(p_ent) {
-                        pcmd.insert(DyingFade {
-                            timer: Timer::from_seconds(0.4, TimerMode::Once),
-                        });
-                        info!("Player dying fade started");
-                    }
-                }
+                damage_player(&mut commands, p_ent, p_tf, &mut hp, &mut stats, &mut events, practice.is_some() || god.0);
+                // 한 번 맞으면 무적이 되므로 이번 프레임의 나머지 충돌은 무시
+                break;
+            }
+        }
+    }
+}
+
+// 플레이어 체력 감소. 0이 되면 페이드아웃 시작
+fn damage_player(
+    commands: &mut Commands,
+    player: Entity,
+    tf: &Transform,
+    hp: &mut Health,
+    stats: &mut RunStats,
+    events: &mut EventWriter<GameplayEvent>,
+    infinite_hp: bool,
+) {
+    // 연습 모드는 피격 연출만 하고 체력은 줄지 않음
+    if hp.current > 0 && !infinite_hp {
+        hp.current -= 1;
+    }
+    stats.damage_taken += 1;
+    let position = tf.translation.truncate();
+    events.send(GameplayEvent::PlayerHurt { position });
+
+    let Some(mut pcmd) = commands.get_entity(player) else {
+        return;
+    };
+    if hp.current == 0 {
+        pcmd.insert(DyingFade {
+            timer: Timer::from_seconds(0.4, TimerMode::Once),
+        });
+        events.send(GameplayEvent::PlayerDied { position });
+        debug!("Player dying fade started");
+    } else {
+        pcmd.insert(Invulnerable {
+            timer: Timer::from_seconds(HURT_INVULNERABLE_SECS, TimerMode::Once),
+            blink: Timer::from_seconds(0.1, TimerMode::Repeating),
+        });
+    }
+}
+
+// 사격 적이 주기적으로 플레이어를 향해 발사
+#[allow(clippy::type_complexity)]
+fn enemy_fire_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    difficulty: Res<Difficulty>,
+    players: Query<&Transform, (With<Player>, Without<DyingFade>)>,
+    mut guns: Query<(&Transform, &mut EnemyGun), (With<Enemy>, Without<DyingFade>)>,
+) {
+    if players.is_empty() {
+        return;
+    }
+    let speed = ENEMY_BULLET_SPEED * difficulty.params().bullet_speed;
+    for (tf, mut gun) in &mut guns {
+        gun.timer.tick(time.delta());
+        let origin = tf.translation.truncate();
+        // 화면 안에 들어온 뒤부터 사격
+        if !gun.timer.just_finished() || !playfield.contains(origin, -20.0) {
+            continue;
+        }
+        let dir = nearest_player(origin, &players).map_or(Vec2::NEG_X, |target| {
+            (target - origin).normalize_or(Vec2::NEG_X)
+        });
+        spawn_enemy_bullet(&mut commands, origin, dir * speed);
+    }
+}
+
+// 조준 대상: 가장 가까운 플레이어 위치
+fn nearest_player<F: bevy::ecs::query::QueryFilter>(
+    origin: Vec2,
+    players: &Query<&Transform, F>,
+) -> Option<Vec2> {
+    players
+        .iter()
+        .map(|tf| tf.translation.truncate())
+        .min_by(|a, b| a.distance_squared(origin).total_cmp(&b.distance_squared(origin)))
+}
+
+fn spawn_enemy_bullet(commands: &mut Commands, origin: Vec2, velocity: Vec2) -> Entity {
+    commands.spawn((
+        Sprite::from_color(Color::srgb(1.0, 0.35, 0.3), Vec2::splat(10.0)),
+        Transform::from_translation(origin.extend(0.15)),
+        Velocity2D {
+            x: velocity.x,
+            y: velocity.y,
+        },
+        Collider {
+            offset: Vec3::ZERO,
+            scale: Vec3::new(5.0, 5.0, 1.0),
+        },
+        EnemyBullet,
+    ))
+    .id()
+}
+
+fn enemy_bullet_update_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    mut q: Query<(Entity, &mut Transform, &Velocity2D), With<EnemyBullet>>,
+) {
+    for (e, mut tf, velocity) in &mut q {
+        tf.translation.x += velocity.x * time.delta_secs();
+        tf.translation.y += velocity.y * time.delta_secs();
+        if !playfield.contains(tf.translation.truncate(), 40.0) {
+            commands.entity(e).despawn_recursive();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn enemy_bullet_hit_system(
+    mut commands: Commands,
+    mut players: Query<(Entity, &Transform, &Collider, &mut Health), (With<Player>, Without<DyingFade>, Without<Invulnerable>)>,
+    bullets: Query<(Entity, &Transform, &Collider), With<EnemyBullet>>,
+    mut stats: ResMut<RunStats>,
+    mut events: EventWriter<GameplayEvent>,
+    practice: Option<Res<practice::PracticeRun>>,
+    god: Res<GodMode>,
+) {
+    let mut consumed = Vec::new();
+    for (p_ent, p_tf, p_col, mut hp) in &mut players {
+        for (b_ent, b_tf, b_col) in &bullets {
+            if consumed.contains(&b_ent) {
+                continue;
+            }
+            if (p_tf, p_col).check_collision(&(b_tf, b_col)) {
+                commands.entity(b_ent).despawn_recursive();
+                consumed.push(b_ent);
+                damage_player(&mut commands, p_ent, p_tf, &mut hp, &mut stats, &mut events, practice.is_some() || god.0);
+                break;
             }
         }
     }
@@ -351,105 +1214,180 @@ fn enemy_spawn_system(
     spawner: Option<ResMut<EnemySpawner>>,
     mut commands: Commands,
     frame_cache: ResMut<FrameCache>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    playfield: Res<Playfield>,
+    prompt: Option<Res<ContinuePrompt>>,
+    difficulty: Res<Difficulty>,
 ) {
     let Some(mut spawner) = spawner else {
         return;
     };
+    // 이어하기 대기 중이거나 이번 웨이브를 다 내보냈으면 새 적을 내보내지 않음
+    if prompt.is_some() || spawner.remaining == Some(0) {
+        return;
+    }
     spawner.timer.tick(time.delta());
     if !spawner.timer.just_finished() {
         return;
     }
 
-    let window = windows.single();
-    let half_w = window.width() / 2.0;
-    let half_h = window.height() / 2.0;
+    // 난이도 배율은 열마다 다시 계산 (적응형 보정이 바로 반영되도록)
+    let params = difficulty.params();
+    let speed = spawner.speed * params.enemy_speed;
+    let interval = spawner.col_spacing / (speed * params.spawn_density);
+    spawner.timer.set_duration(std::time::Duration::from_secs_f32(interval));
+    let health = ENEMY_HEALTH as f32 * params.enemy_health * spawner.health_scale;
+    let column = spawner.columns;
+    spawner.columns += 1;
+    if let Some(remaining) = spawner.remaining.as_mut() {
+        *remaining -= 1;
+    }
+
+    let half_w = playfield.half_size().x;
+    let half_h = playfield.half_size().y;
     let rows = (half_h * 2.0 / spawner.row_height).floor().max(1.0) as u32;
     let start_x = half_w + spawner.margin;
     let frames = frame_cache.map.get("enemy").expect("Missing frames");
     for r in 0..rows {
         let y = -half_h + spawner.row_height * (r as f32 + 0.5);
-        let enemy_entity = spawn_entity(
-            &mut commands,
-            Animation {
-                frames: frames.clone(),
-                timer: Timer::from_seconds(0.15, TimerMode::Repeating),
-                current_frame_index: 0,
-                state: AnimationPlaybackState::Playing,
+        let archetype = spawner.pick_archetype(column, r);
+        spawn_enemy(&mut commands, frames.clone(), archetype, Vec2::new(start_x, y), speed, health);
+    }
+}
+
+// 종류별 적 생성. health는 종류 배율 적용 전 값
+fn spawn_enemy(
+    commands: &mut Commands,
+    frames: Vec<Handle<Image>>,
+    archetype: EnemyArchetype,
+    position: Vec2,
+    speed: f32,
+    health: f32,
+) -> Entity {
+    let health = ((health * archetype.health_factor()).round() as u32).max(1);
+    let enemy_entity = spawn_entity(
+        commands,
+        Animation {
+            frames,
+            timer: Timer::from_seconds(0.15, TimerMode::Repeating),
+            current_frame_index: 0,
+            state: AnimationPlaybackState::Playing,
+        },
+        position.extend(0.0),
+        Vec3::splat(archetype.scale()),
+    );
+    commands
+        .entity(enemy_entity)
+        .insert((
+            Velocity2D {
+                x: speed * archetype.speed_factor(),
+                y: 0.0,
             },
-            Vec3::new(start_x, y, 0.0),
-            Vec3::ONE,
-        );
-        commands
-            .entity(enemy_entity)
-            .insert((
-                Velocity2D {
-                    x: spawner.speed,
-                    y,
-                },
-                Enemy {}, 
-                Health { current: 5, max: 5 },
-                Collider {
-                    offset: Vec3::ZERO,
-                    scale: Vec3::ONE,
-                },
-                AutoSizeCollider { multiplier: Vec2::new(0.80, 0.80), padding: Vec2::ZERO },
-            ));
-        info!("Enemy spawned at y={:.1}", y);
+            Enemy {},
+            archetype,
+            Health { current: health, max: health },
+            Collider {
+                offset: Vec3::ZERO,
+                scale: Vec3::ONE,
+            },
+            AutoSizeCollider { multiplier: Vec2::new(0.80, 0.80), padding: Vec2::ZERO },
+        ));
+    match archetype {
+        EnemyArchetype::Gunner => {
+            commands.entity(enemy_entity).insert(EnemyGun {
+                timer: Timer::from_seconds(2.4, TimerMode::Repeating),
+            });
+        }
+        EnemyArchetype::Weaver => {
+            commands.entity(enemy_entity).insert(Weave {
+                base_y: position.y,
+                // 같은 열의 적들이 서로 다른 위상으로 흔들리도록
+                phase: position.y * 0.05,
+                amplitude: 45.0,
+            });
+        }
+        EnemyArchetype::Grunt | EnemyArchetype::Tank => {}
     }
+    trace!("Enemy ({:?}) spawned at y={:.1}", archetype, position.y);
+    enemy_entity
 }
 
 // 왼쪽 화면 밖으로 나간 적들을 despawn
+#[allow(clippy::type_complexity)]
 fn enemy_despawn_offscreen_system(
     mut commands: Commands,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    q: Query<(Entity, &Transform), With<Enemy>>,
+    playfield: Res<Playfield>,
+    q: Query<(Entity, &Transform), (With<Enemy>, Without<Boss>)>,
 ) {
-    let window = windows.single();
-    let half_w = window.width() / 2.0;
+    let half_w = playfield.half_size().x;
     let margin = 60.0;
     for (e, tf) in &q {
         if tf.translation.x < -half_w - margin {
             commands.entity(e).despawn_recursive();
-            info!("Enemy despawned offscreen");
+            trace!("Enemy despawned offscreen");
         }
     }
 }
 
 // 총알-적 충돌 처리
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn bullet_enemy_hit_system(
     mut commands: Commands,
-    mut bullets: Query<(Entity, &Transform), With<Bullet>>,
+    mut bullets: Query<(Entity, &Transform, &Bullet)>,
     mut enemies: Query<
-        (Entity, &Transform, Option<&mut Health>),
+        (Entity, &Transform, Option<&mut Health>, Has<Boss>, Option<&EnemyArchetype>),
         (With<Enemy>, Without<DyingFade>),
     >,
+    mut score: ResMut<Score>,
+    difficulty: Res<Difficulty>,
+    mut stats: ResMut<RunStats>,
+    mut events: EventWriter<GameplayEvent>,
 ) {
     // 단순 근접 판정 (원 충돌)
-    let bullet_r = 12.0f32;
-    let enemy_r = 28.0f32;
+    let bullet_r = BULLET_HIT_RADIUS;
+    let enemy_r = ENEMY_HIT_RADIUS;
 
-    for (b_ent, b_tf) in &mut bullets {
+    // 이번 프레임에 격추한 적. DyingFade는 명령이 적용된 뒤에야 붙으므로 여기서 걸러낸다
+    let mut killed = Vec::new();
+    for (b_ent, b_tf, bullet) in &mut bullets {
         let b_pos = b_tf.translation.truncate();
-        for (e_ent, e_tf, health_opt) in &mut enemies {
+        for (e_ent, e_tf, health_opt, is_boss, archetype) in &mut enemies {
+            if killed.contains(&e_ent) {
+                continue;
+            }
             let e_pos = e_tf.translation.truncate();
             let dist2 = b_pos.distance_squared(e_pos);
-            if dist2 <= (bullet_r + enemy_r) * (bullet_r + enemy_r) {
-                if let Some(mut bcmd) = commands.get_entity(b_ent) {
+            // 보스처럼 확대된 적은 판정 반경도 함께 키움
+            let hit_r = bullet_r + enemy_r * e_tf.scale.x.abs();
+            if dist2 <= hit_r * hit_r {
+                if let Some(bcmd) = commands.get_entity(b_ent) {
                     bcmd.despawn_recursive();
                 }
+                stats.shots_hit += 1;
                 if let Some(mut health) = health_opt {
                     let before = health.current;
                     if health.current > 0 { health.current -= 1; }
-                    info!("Bullet-Enemy HIT: hp {} -> {}", before, health.current);
-                    if health.current == 0 {
+                    debug!("Bullet-Enemy HIT: hp {} -> {}", before, health.current);
+                    if before > 0 && health.current == 0 {
+                        killed.push(e_ent);
                         // 페이드아웃 시작
                         if let Some(mut ecmd) = commands.get_entity(e_ent) {
                             ecmd.insert(DyingFade { timer: Timer::from_seconds(0.4, TimerMode::Once) });
-                            info!("Enemy dying fade started");
+                            debug!("Enemy dying fade started");
                         } else {
-                            info!("Skip fade: enemy already despawned");
+                            trace!("Skip fade: enemy already despawned");
                         }
+                        events.send(GameplayEvent::EnemyKilled {
+                            position: e_tf.translation.truncate(),
+                        });
+                        let base_score = if is_boss {
+                            BOSS_SCORE as f32
+                        } else {
+                            ENEMY_SCORE as f32 * archetype.map_or(1.0, |a| a.score_factor())
+                        };
+                        score.add(bullet.owner, (base_score * difficulty.params().score_multiplier) as u32);
+                        stats.kills += 1;
+                    } else {
+                        events.send(GameplayEvent::EnemyHit { position: b_pos });
                     }
                 }
                 break;
@@ -459,13 +1397,24 @@ fn bullet_enemy_hit_system(
 }
 
 // 페이드아웃 진행
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn enemy_fadeout_system(
     time: Res<Time>,
     mut commands: Commands,
-    mut next_state: ResMut<NextState<super::super::scene_states::SceneStatus>>,
-    mut q: Query<(Entity, &mut DyingFade, &mut Sprite, Option<&Player>)>,
+    mut lives: ResMut<Lives>,
+    mut queue: ResMut<RespawnQueue>,
+    players: Query<Entity, With<Player>>,
+    mut stage: Option<ResMut<StageRun>>,
+    run_mode: Res<RunMode>,
+    mut survival_scores: ResMut<SurvivalScores>,
+    score: Res<Score>,
+    stats: Res<RunStats>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+    mut q: Query<(Entity, &mut DyingFade, &mut Sprite, Option<&PlayerId>, Has<Boss>)>,
 ) {
-    for (e, mut fading, mut sprite, is_player) in &mut q {
+    // 이번 프레임에 사라진 플레이어. despawn은 명령이 적용될 때까지 미뤄지므로 따로 센다
+    let mut gone = Vec::new();
+    for (e, mut fading, mut sprite, player, is_boss) in &mut q {
         fading.timer.tick(time.delta());
         let total = fading.timer.duration().as_secs_f32().max(0.0001);
         let elapsed = fading.timer.elapsed().as_secs_f32().min(total);
@@ -473,12 +1422,450 @@ fn enemy_fadeout_system(
         sprite.color.set_alpha(alpha);
         if fading.timer.finished() {
             commands.entity(e).despawn_recursive();
-            if is_player.is_some() {
-                info!("Player despawned -> GameOver");
-                next_state.set(super::super::scene_states::SceneStatus::GameOver);
+            if let Some(&player) = player {
+                // 다른 플레이어가 아직 쓰고 있는 기체 (화면 위 + 재등장 대기)
+                gone.push(e);
+                let others_in_play = players.iter().filter(|other| !gone.contains(other)).count() + queue.0.len();
+                if lives.lose(player, others_in_play as u32) {
+                    debug!("Player {} down, lives left: {}", player.label(), lives.of(player));
+                    queue.0.push((player, Timer::from_seconds(1.0, TimerMode::Once)));
+                } else if others_in_play > 0 {
+                    // 다른 플레이어가 남아 있으면 계속 진행
+                    info!("Player {} out of lives", player.label());
+                } else if *run_mode == RunMode::Survival {
+                    // 생존 모드는 이어하기 없이 기록을 남기고 종료
+                    let rank = survival_scores.record(stats.elapsed, score.value);
+                    survival_scores.save();
+                    info!("Survival over after {:.1}s, rank {:?}", stats.elapsed, rank);
+                    commands.insert_resource(SurvivalResult {
+                        time: stats.elapsed,
+                        score: score.value,
+                        rank,
+                    });
+                    next_state.set(scene_ids::GAME_OVER);
+                } else {
+                    info!("All players down -> Continue prompt");
+                    commands.insert_resource(ContinuePrompt {
+                        countdown: Timer::from_seconds(CONTINUE_SECONDS, TimerMode::Once),
+                    });
+                    spawn_continue_screen(&mut commands);
+                }
+            } else if is_boss {
+                info!("Boss defeated");
+                if let Some(stage) = stage.as_mut() {
+                    stage.phase = StagePhase::Cleared(Timer::from_seconds(1.5, TimerMode::Once));
+                }
             } else {
-                info!("Enemy despawned after fade");
+                trace!("Enemy despawned after fade");
+            }
+        }
+    }
+}
+
+impl EnemySpawner {
+    fn apply_curve(&mut self, curve: &SurvivalCurve, elapsed: f32) {
+        let point = curve.sample(elapsed);
+        self.speed = point.speed;
+        self.col_spacing = point.col_spacing;
+        self.health_scale = point.health_scale;
+        self.mix = curve.mix_at(elapsed);
+    }
+
+    // 이번 칸에 나올 적 종류. 열/행 번호로 정해지므로 같은 판은 같은 배치
+    fn pick_archetype(&self, column: u32, row: u32) -> EnemyArchetype {
+        if self.gunner_every > 0 && (column + row).is_multiple_of(self.gunner_every) {
+            return EnemyArchetype::Gunner;
+        }
+        let total: u32 = self.mix.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return EnemyArchetype::Grunt;
+        }
+        let hash = column.wrapping_mul(2_654_435_761) ^ row.wrapping_mul(40_503).rotate_left(16);
+        let mut roll = hash % total;
+        for &(archetype, weight) in &self.mix {
+            if roll < weight {
+                return archetype;
             }
+            roll -= weight;
+        }
+        EnemyArchetype::Grunt
+    }
+
+    // 처음부터 until초까지 진행했을 때와 같은 열 번호, 곡선 상태, 다음 열까지 남은 시간으로 맞춤
+    fn fast_forward(&mut self, curve: &SurvivalCurve, difficulty: &Difficulty, until: f32) {
+        let params = difficulty.params();
+        let interval_at = |t: f32| {
+            let point = curve.sample(t);
+            point.col_spacing / (point.speed * params.enemy_speed * params.spawn_density)
+        };
+        let mut t = 0.0;
+        let mut interval = interval_at(t);
+        while t + interval <= until {
+            t += interval;
+            self.columns += 1;
+            interval = interval_at(t);
+        }
+        self.apply_curve(curve, until);
+        self.timer = Timer::from_seconds(interval, TimerMode::Repeating);
+        self.timer.set_elapsed(std::time::Duration::from_secs_f32(until - t));
+    }
+
+    // 웨이브 설정을 적용하고 해당 웨이브의 열 수만큼만 내보내도록 설정
+    fn apply_wave(&mut self, wave: &WaveDef) {
+        self.row_height = wave.row_height;
+        self.speed = wave.speed;
+        self.col_spacing = wave.col_spacing;
+        self.gunner_every = wave.gunner_every;
+        self.remaining = Some(wave.columns);
+        self.timer = Timer::from_seconds(wave.col_spacing / wave.speed, TimerMode::Repeating);
+    }
+}
+
+// 결과 화면용 플레이 시간 누적
+fn run_clock_system(time: Res<Time>, mut stats: ResMut<RunStats>) {
+    stats.elapsed += time.delta_secs();
+}
+
+// 캠페인 진행: 웨이브를 순서대로 내보내고, 마지막에 보스 등장, 격파하면 결과 화면으로
+#[allow(clippy::too_many_arguments)]
+fn stage_progress_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    stage: Option<ResMut<StageRun>>,
+    mut spawner: ResMut<EnemySpawner>,
+    campaign: Res<Campaign>,
+    mut progress: ResMut<CampaignProgress>,
+    mut music: ResMut<MusicState>,
+    frame_cache: Res<FrameCache>,
+    playfield: Res<Playfield>,
+    difficulty: Res<Difficulty>,
+    score: Res<Score>,
+    stats: Res<RunStats>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+) {
+    let Some(mut stage) = stage else {
+        return;
+    };
+    let Some(def) = campaign.stage(stage.stage) else {
+        return;
+    };
+
+    let stage = &mut *stage;
+    match &mut stage.phase {
+        StagePhase::Waves => {
+            if spawner.remaining != Some(0) {
+                return;
+            }
+            // 마지막 열을 내보낸 뒤 웨이브 간 대기
+            if stage.pause.duration().is_zero() {
+                let pause_after = def.waves.get(stage.wave).map_or(0.0, |w| w.pause_after);
+                stage.pause = Timer::from_seconds(pause_after.max(0.01), TimerMode::Once);
+            }
+            stage.pause.tick(time.delta());
+            if !stage.pause.finished() {
+                return;
+            }
+            stage.wave += 1;
+            stage.pause = Timer::from_seconds(0.0, TimerMode::Once);
+            if let Some(wave) = def.waves.get(stage.wave) {
+                spawner.apply_wave(wave);
+                info!("Wave {} started", stage.wave + 1);
+            } else {
+                // 웨이브를 모두 내보냈으면 보스 등장
+                let frames = frame_cache.map.get("enemy").expect("Missing frames");
+                spawn_boss(&mut commands, frames.clone(), &def.boss, &playfield, &difficulty);
+                music.request(MusicCue::Boss);
+                stage.phase = StagePhase::Boss;
+                info!("Boss incoming");
+            }
+        }
+        StagePhase::Boss => {}
+        StagePhase::Cleared(timer) => {
+            timer.tick(time.delta());
+            if !timer.just_finished() {
+                return;
+            }
+            let unlocked_next = progress.record_clear(stage.stage, score.value, campaign.stages.len());
+            progress.save();
+            commands.insert_resource(StageResult {
+                stage: stage.stage,
+                stats: *stats,
+                score: score.value,
+                unlocked_next,
+            });
+            next_state.set(scene_ids::STAGE_CLEAR);
+        }
+    }
+}
+
+fn spawn_boss(
+    commands: &mut Commands,
+    frames: Vec<Handle<Image>>,
+    def: &BossDef,
+    playfield: &Playfield,
+    difficulty: &Difficulty,
+) {
+    let half_w = playfield.half_size().x;
+    let health = ((def.health as f32 * difficulty.params().enemy_health).round() as u32).max(1);
+    insert_boss(
+        commands,
+        frames,
+        Vec3::new(half_w + 200.0, 0.0, 0.05),
+        Vec3::splat(def.scale),
+        Boss {
+            target_x: half_w - 220.0,
+            phase: 0.0,
+            spread: def.spread,
+            fire_timer: Timer::from_seconds(def.fire_interval, TimerMode::Repeating),
+        },
+        Health { current: health, max: health },
+    );
+}
+
+// 보스 엔티티와 상단 체력바 생성
+fn insert_boss(
+    commands: &mut Commands,
+    frames: Vec<Handle<Image>>,
+    position: Vec3,
+    scale: Vec3,
+    boss: Boss,
+    health: Health,
+) -> Entity {
+    let boss_entity = spawn_entity(
+        commands,
+        Animation {
+            frames,
+            timer: Timer::from_seconds(0.2, TimerMode::Repeating),
+            current_frame_index: 0,
+            state: AnimationPlaybackState::Playing,
+        },
+        position,
+        scale,
+    );
+    commands.entity(boss_entity).insert((
+        Enemy {},
+        boss,
+        health,
+        Collider {
+            offset: Vec3::ZERO,
+            scale: Vec3::ONE,
+        },
+        AutoSizeCollider { multiplier: Vec2::new(0.7, 0.7), padding: Vec2::ZERO },
+    ));
+
+    // 상단 중앙 보스 체력바
+    commands
+        .spawn((
+            BossHealthBar,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(64.0),
+                left: Val::Percent(25.0),
+                width: Val::Percent(50.0),
+                height: Val::Px(14.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.7)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                BossHealthFill,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                },
+                BackgroundColor(Color::srgb(0.9, 0.2, 0.25)),
+            ));
+        });
+    boss_entity
+}
+
+// 보스 진입 후 위아래 왕복, 주기적으로 플레이어를 향해 부채꼴 발사
+fn boss_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    difficulty: Res<Difficulty>,
+    players: Query<&Transform, (With<Player>, Without<Boss>)>,
+    mut bosses: Query<(&mut Boss, &mut Transform), Without<DyingFade>>,
+) {
+    let dt = time.delta_secs();
+    for (mut boss, mut tf) in &mut bosses {
+        if tf.translation.x > boss.target_x {
+            tf.translation.x = (tf.translation.x - 160.0 * dt).max(boss.target_x);
+            continue;
+        }
+        boss.phase += dt * 0.8;
+        tf.translation.y = boss.phase.sin() * playfield.half_size().y * 0.6;
+
+        boss.fire_timer.tick(time.delta());
+        if !boss.fire_timer.just_finished() {
+            continue;
         }
+        let origin = tf.translation.truncate();
+        let aim = nearest_player(origin, &players)
+            .map_or(Vec2::NEG_X, |target| (target - origin).normalize_or(Vec2::NEG_X));
+        let speed = ENEMY_BULLET_SPEED * 0.9 * difficulty.params().bullet_speed;
+        let arc = 1.0; // 부채꼴 전체 각도(라디안)
+        let count = boss.spread.max(1);
+        for i in 0..count {
+            let t = if count == 1 { 0.5 } else { i as f32 / (count - 1) as f32 };
+            let dir = Vec2::from_angle(aim.to_angle() + (t - 0.5) * arc);
+            spawn_enemy_bullet(&mut commands, origin, dir * speed);
+        }
+    }
+}
+
+// 보스 체력바 갱신. 보스가 사라지면 체력바도 제거
+fn boss_health_bar_system(
+    mut commands: Commands,
+    bosses: Query<&Health, With<Boss>>,
+    bars: Query<Entity, With<BossHealthBar>>,
+    mut fills: Query<&mut Node, With<BossHealthFill>>,
+) {
+    let Ok(health) = bosses.get_single() else {
+        for bar in &bars {
+            commands.entity(bar).despawn_recursive();
+        }
+        return;
+    };
+    let frac = if health.max > 0 {
+        health.current as f32 / health.max as f32
+    } else {
+        0.0
+    };
+    for mut node in &mut fills {
+        node.width = Val::Percent(frac.clamp(0.0, 1.0) * 100.0);
+    }
+}
+
+// 생존 모드: 경과 시간에 따라 곡선을 따라 스포너 설정을 올림
+fn survival_ramp_system(
+    run_mode: Res<RunMode>,
+    curve: Res<SurvivalCurve>,
+    stats: Res<RunStats>,
+    mut spawner: ResMut<EnemySpawner>,
+    mut pacing: ResMut<ScrollPacing>,
+) {
+    if !run_mode.follows_curve() {
+        return;
+    }
+    let mix_len = spawner.mix.len();
+    spawner.apply_curve(&curve, stats.elapsed);
+    pacing.speed = spawner.speed * 0.66;
+    if spawner.mix.len() > mix_len
+        && let Some((archetype, _)) = spawner.mix.last()
+    {
+        info!("Survival {:.0}s: {:?} enemies join", stats.elapsed, archetype);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn finished_fade() -> DyingFade {
+        let mut timer = Timer::from_seconds(0.5, TimerMode::Once);
+        timer.tick(std::time::Duration::from_secs_f32(0.5));
+        DyingFade { timer }
+    }
+
+    fn fadeout_world(lives: Lives) -> World {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(lives);
+        world.insert_resource(RespawnQueue::default());
+        world.insert_resource(RunMode::Classic);
+        world.insert_resource(SurvivalScores { entries: Vec::new() });
+        world.insert_resource(Score::default());
+        world.insert_resource(RunStats::default());
+        world.insert_resource(NextState::<SceneStatus>::default());
+        world
+    }
+
+    fn spawn_dying_player(world: &mut World, id: PlayerId) -> Entity {
+        let weapon = Weapon::for_player(id);
+        world
+            .spawn((
+                Player {
+                    fire_timer: Timer::from_seconds(weapon.interval(), TimerMode::Repeating),
+                    weapon,
+                },
+                id,
+                Sprite::default(),
+                finished_fade(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn two_bullets_on_the_same_frame_kill_once() {
+        let mut world = World::new();
+        world.insert_resource(Score::default());
+        world.insert_resource(Difficulty::default());
+        world.insert_resource(RunStats::default());
+        world.init_resource::<Events<GameplayEvent>>();
+        world.spawn((
+            Enemy {},
+            Transform::from_xyz(100.0, 0.0, 0.0),
+            Health { current: 1, max: 1 },
+        ));
+        for _ in 0..2 {
+            world.spawn((
+                Bullet {
+                    life: Timer::from_seconds(1.0, TimerMode::Once),
+                    owner: PlayerId::One,
+                },
+                Transform::from_xyz(100.0, 0.0, 0.0),
+            ));
+        }
+
+        world.run_system_once(bullet_enemy_hit_system).unwrap();
+
+        let kills = world
+            .resource::<Events<GameplayEvent>>()
+            .iter_current_update_events()
+            .filter(|event| matches!(event, GameplayEvent::EnemyKilled { .. }))
+            .count();
+        assert_eq!(kills, 1);
+        assert_eq!(world.resource::<RunStats>().kills, 1);
+        assert_eq!(world.resource::<RunStats>().shots_hit, 1);
+        let expected = (ENEMY_SCORE as f32 * Difficulty::default().params().score_multiplier) as u32;
+        assert_eq!(world.resource::<Score>().value, expected);
+    }
+
+    #[test]
+    fn both_players_out_on_the_same_frame_prompts_continue() {
+        let mut lives = Lives::new(2, false);
+        lives.remaining[..2].fill(1);
+        let mut world = fadeout_world(lives);
+        let one = spawn_dying_player(&mut world, PlayerId::One);
+        let two = spawn_dying_player(&mut world, PlayerId::Two);
+
+        world.run_system_once(enemy_fadeout_system).unwrap();
+
+        assert!(world.get_entity(one).is_err() && world.get_entity(two).is_err());
+        assert!(world.contains_resource::<ContinuePrompt>());
+        let screens = world.query_filtered::<(), With<ContinueScreen>>().iter(&world).count();
+        assert_eq!(screens, 1);
+    }
+
+    #[test]
+    fn shared_lives_on_the_same_frame_prompt_continue_once() {
+        let mut lives = Lives::new(2, true);
+        lives.remaining[0] = 2;
+        let mut world = fadeout_world(lives);
+        spawn_dying_player(&mut world, PlayerId::One);
+        spawn_dying_player(&mut world, PlayerId::Two);
+
+        world.run_system_once(enemy_fadeout_system).unwrap();
+
+        assert!(world.resource::<RespawnQueue>().0.is_empty());
+        assert!(world.contains_resource::<ContinuePrompt>());
+        let screens = world.query_filtered::<(), With<ContinueScreen>>().iter(&world).count();
+        assert_eq!(screens, 1);
     }
 }
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use super::*;
+use crate::game::console::app_extensions::{AppConsoleExtensions, ConsoleResult};
+
+// 켜져 있으면 플레이어 체력이 줄지 않음. 씬을 옮겨도 유지된다
+#[derive(Resource, Default)]
+pub(super) struct GodMode(pub(super) bool);
+
+pub fn add_console_commands(app: &mut App) {
+    app.init_resource::<GodMode>()
+        .add_console_command(
+            "spawn",
+            "spawn enemy <grunt|gunner|weaver|tank> <x> <y>",
+            &["enemy"],
+            spawn_command,
+        )
+        .add_console_command("god", "god", &[], god_command)
+        .add_console_command("hp", "hp <n>", &[], hp_command)
+        .add_console_command("score", "score <n>", &[], score_command)
+        .add_console_command("wave", "wave <n>", &[], wave_command);
+}
+
+fn parse_arg<T: std::str::FromStr>(args: &[String], index: usize, name: &str) -> Result<T, String> {
+    let arg = args.get(index).ok_or_else(|| format!("missing <{}>", name))?;
+    arg.parse().map_err(|_| format!("invalid <{}>: {}", name, arg))
+}
+
+fn parse_archetype(name: &str) -> Option<EnemyArchetype> {
+    [
+        EnemyArchetype::Grunt,
+        EnemyArchetype::Gunner,
+        EnemyArchetype::Weaver,
+        EnemyArchetype::Tank,
+    ]
+    .into_iter()
+    .find(|archetype| format!("{:?}", archetype).eq_ignore_ascii_case(name))
+}
+
+// 지정한 위치에 적 생성 (플레이필드 좌표, 중앙이 원점)
+fn spawn_command(
+    In(args): In<Vec<String>>,
+    mut commands: Commands,
+    frame_cache: Res<FrameCache>,
+    spawner: Option<Res<EnemySpawner>>,
+    difficulty: Res<Difficulty>,
+) -> ConsoleResult {
+    if args.first().map(String::as_str) != Some("enemy") {
+        return Err("usage: spawn enemy <archetype> <x> <y>".to_string());
+    }
+    let name: String = parse_arg(&args, 1, "archetype")?;
+    let archetype = parse_archetype(&name).ok_or_else(|| format!("unknown archetype '{}'", name))?;
+    let x: f32 = parse_arg(&args, 2, "x")?;
+    let y: f32 = parse_arg(&args, 3, "y")?;
+    let (Some(spawner), Some(frames)) = (spawner, frame_cache.map.get("enemy")) else {
+        return Err("not in game".to_string());
+    };
+    let params = difficulty.params();
+    let health = ENEMY_HEALTH as f32 * params.enemy_health * spawner.health_scale;
+    spawn_enemy(
+        &mut commands,
+        frames.clone(),
+        archetype,
+        Vec2::new(x, y),
+        spawner.speed * params.enemy_speed,
+        health,
+    );
+    Ok(format!("spawned {:?} at ({}, {})", archetype, x, y))
+}
+
+fn god_command(In(_): In<Vec<String>>, mut god: ResMut<GodMode>) -> ConsoleResult {
+    god.0 = !god.0;
+    Ok(format!("god mode {}", if god.0 { "on" } else { "off" }))
+}
+
+// 모든 플레이어 체력 설정. 최대치보다 크면 최대치도 늘림
+fn hp_command(
+    In(args): In<Vec<String>>,
+    mut players: Query<&mut Health, (With<Player>, Without<DyingFade>)>,
+) -> ConsoleResult {
+    let hp: u32 = parse_arg(&args, 0, "n")?;
+    if hp == 0 {
+        return Err("hp must be at least 1".to_string());
+    }
+    if players.is_empty() {
+        return Err("no players".to_string());
+    }
+    for mut health in &mut players {
+        health.max = health.max.max(hp);
+        health.current = hp;
+    }
+    Ok(format!("hp {}", hp))
+}
+
+// 합계 점수를 설정 (1P 점수에 반영)
+fn score_command(In(args): In<Vec<String>>, mut score: ResMut<Score>) -> ConsoleResult {
+    let value: u32 = parse_arg(&args, 0, "n")?;
+    score.per_player = [0; MAX_PLAYERS];
+    score.per_player[PlayerId::One.index()] = value;
+    score.value = value;
+    Ok(format!("score {}", value))
+}
+
+// 캠페인 스테이지의 n번째 웨이브(1부터)로 이동
+fn wave_command(
+    In(args): In<Vec<String>>,
+    stage: Option<ResMut<StageRun>>,
+    spawner: Option<ResMut<EnemySpawner>>,
+    campaign: Res<Campaign>,
+) -> ConsoleResult {
+    let wave: usize = parse_arg(&args, 0, "n")?;
+    let (Some(mut stage), Some(mut spawner)) = (stage, spawner) else {
+        return Err("wave only works in a campaign stage".to_string());
+    };
+    let def = campaign.stage(stage.stage).ok_or("unknown stage")?;
+    let index = wave.checked_sub(1).filter(|&i| i < def.waves.len()).ok_or_else(|| {
+        format!("wave must be between 1 and {}", def.waves.len())
+    })?;
+    spawner.apply_wave(&def.waves[index]);
+    stage.wave = index;
+    stage.pause = Timer::from_seconds(0.0, TimerMode::Once);
+    stage.phase = StagePhase::Waves;
+    Ok(format!("wave {}/{}", wave, def.waves.len()))
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+use super::practice::PracticeRun;
+use super::*;
+use crate::game::debug::overlay::DebugOverlay;
+
+// 충돌 판정 영역 표시: 콜라이더 AABB와 총알-적 원 판정. F3 오버레이나 연습 모드 H로 켠다
+pub(super) fn hitbox_gizmo_system(
+    overlay: Res<DebugOverlay>,
+    practice: Option<Res<PracticeRun>>,
+    mut gizmos: Gizmos,
+    colliders: Query<(&Transform, &Collider, Has<Player>)>,
+    bullets: Query<&Transform, With<Bullet>>,
+    enemies: Query<&Transform, (With<Enemy>, Without<DyingFade>)>,
+) {
+    if !overlay.enabled && !practice.is_some_and(|p| p.show_hitboxes()) {
+        return;
+    }
+    for (tf, collider, is_player) in &colliders {
+        let scale = tf.scale.truncate().abs();
+        let center = tf.translation.truncate() + collider.offset.truncate() * scale;
+        let size = collider.scale.truncate().abs() * scale * 2.0;
+        let color = if is_player {
+            Color::srgb(0.3, 1.0, 0.4)
+        } else {
+            Color::srgb(1.0, 0.35, 0.3)
+        };
+        gizmos.rect_2d(center, size, color);
+    }
+    for tf in &bullets {
+        gizmos.circle_2d(tf.translation.truncate(), BULLET_HIT_RADIUS, Color::srgb(0.4, 0.8, 1.0));
+    }
+    for tf in &enemies {
+        let radius = ENEMY_HIT_RADIUS * tf.scale.x.abs();
+        gizmos.circle_2d(tf.translation.truncate(), radius, Color::srgb(1.0, 0.8, 0.3));
+    }
+}
+
+// 오버레이에 종류별 엔티티 수와 스포너 상태를 표시
+#[allow(clippy::too_many_arguments)]
+pub(super) fn debug_info_system(
+    mut overlay: ResMut<DebugOverlay>,
+    spawner: Option<Res<EnemySpawner>>,
+    stats: Res<RunStats>,
+    all: Query<()>,
+    players: Query<(), With<Player>>,
+    enemies: Query<(), With<Enemy>>,
+    bullets: Query<(), With<Bullet>>,
+    enemy_bullets: Query<(), With<EnemyBullet>>,
+    particles: Query<(), With<Particle>>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+    overlay.set_section(
+        "Entities",
+        format!(
+            "total {}  players {}  enemies {}\nbullets {}  enemy bullets {}  particles {}",
+            all.iter().count(),
+            players.iter().count(),
+            enemies.iter().count(),
+            bullets.iter().count(),
+            enemy_bullets.iter().count(),
+            particles.iter().count(),
+        ),
+    );
+    let Some(spawner) = spawner else {
+        return;
+    };
+    let remaining = spawner
+        .remaining
+        .map_or("inf".to_string(), |remaining| remaining.to_string());
+    let mix: Vec<String> = spawner
+        .mix
+        .iter()
+        .map(|(archetype, weight)| format!("{:?}x{}", archetype, weight))
+        .collect();
+    overlay.set_section(
+        "Spawner",
+        format!(
+            "t {:.1}s  column {}  remaining {}\nspeed {:.0}  spacing {:.0}  hp x{:.2}\nnext column {:.2}s / {:.2}s\nmix {}",
+            stats.elapsed,
+            spawner.columns,
+            remaining,
+            spawner.speed,
+            spawner.col_spacing,
+            spawner.health_scale,
+            spawner.timer.remaining_secs(),
+            spawner.timer.duration().as_secs_f32(),
+            if mix.is_empty() { "-".to_string() } else { mix.join(" ") },
+        ),
+    );
+}
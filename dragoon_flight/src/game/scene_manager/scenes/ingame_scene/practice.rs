@@ -0,0 +1,106 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use super::snapshot::{SnapshotReader, SnapshotWriter, WorldSnapshot};
+use super::*;
+
+// 되감기용 스냅샷을 찍는 간격과 보관 길이(초)
+const REWIND_INTERVAL: f32 = 0.25;
+const REWIND_HISTORY: f32 = 10.0;
+// R 한 번에 되돌아가는 시간(초)
+const REWIND_STEP: f32 = 3.0;
+
+// 연습 모드 상태. 이 리소스가 있으면 체력이 줄지 않는다
+#[derive(Resource)]
+pub(super) struct PracticeRun {
+    show_hitboxes: bool,
+    history: VecDeque<WorldSnapshot>, // 오래된 것부터
+    capture: Timer,
+}
+
+impl PracticeRun {
+    pub(super) fn new() -> Self {
+        Self {
+            show_hitboxes: false,
+            history: VecDeque::new(),
+            capture: Timer::from_seconds(REWIND_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    pub(super) fn show_hitboxes(&self) -> bool {
+        self.show_hitboxes
+    }
+
+    fn push(&mut self, snapshot: WorldSnapshot) {
+        self.history.push_back(snapshot);
+        let capacity = (REWIND_HISTORY / REWIND_INTERVAL) as usize;
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+    }
+
+    // now에서 REWIND_STEP초 이전(없으면 가장 오래된) 스냅샷. 그보다 새로운 것은 버림
+    fn rewind_target(&mut self, now: f32) -> Option<&WorldSnapshot> {
+        let target = now - REWIND_STEP;
+        while self.history.len() > 1 && self.history.back().is_some_and(|s| s.elapsed() > target) {
+            self.history.pop_back();
+        }
+        self.history.back()
+    }
+}
+
+pub(super) fn spawn_practice_hud(commands: &mut Commands) {
+    commands.spawn((
+        Localized::new("practice-hud"),
+        FontRole::Hud,
+        TextFont { font_size: 18.0, ..Default::default() },
+        TextColor(Color::srgb(0.75, 0.85, 0.95)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(12.0),
+            ..Default::default()
+        },
+    ));
+}
+
+// 연습 모드 입력과 되감기 기록
+pub(super) fn practice_system(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    practice: Option<ResMut<PracticeRun>>,
+    reader: SnapshotReader,
+    mut writer: SnapshotWriter,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+) {
+    let Some(mut practice) = practice else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(scene_ids::PRACTICE_SELECT);
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyH) {
+        practice.show_hitboxes = !practice.show_hitboxes;
+    }
+
+    if keys.just_pressed(KeyCode::KeyR) {
+        let Some(now) = reader.capture().map(|s| s.elapsed()) else {
+            return;
+        };
+        if let Some(snapshot) = practice.rewind_target(now) {
+            writer.restore(snapshot);
+            info!("Rewind {} -> {}", scores::format_time(now), scores::format_time(snapshot.elapsed()));
+        }
+        practice.capture.reset();
+        return;
+    }
+
+    practice.capture.tick(time.delta());
+    if practice.capture.just_finished()
+        && let Some(snapshot) = reader.capture()
+    {
+        practice.push(snapshot);
+    }
+}
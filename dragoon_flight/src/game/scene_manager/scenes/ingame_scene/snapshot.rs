@@ -0,0 +1,476 @@
+use bevy::{ecs::system::SystemParam, prelude::Or, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+// 인게임 월드 전체 상태. 디버그 저장/복원과 되감기에 사용
+// 파티클, 배경 스크롤, 애니메이션 프레임 같은 연출 상태는 담지 않는다
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct WorldSnapshot {
+    players: Vec<PlayerSnapshot>,
+    enemies: Vec<EnemySnapshot>,
+    bullets: Vec<BulletSnapshot>,
+    enemy_bullets: Vec<EnemyBulletSnapshot>,
+    score: Score,
+    spawner: EnemySpawner,
+    lives: Lives,
+    respawn_queue: RespawnQueue,
+    stats: RunStats,
+    stage: Option<StageRun>,
+    continue_prompt: Option<ContinuePrompt>,
+    pressure: f32,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct PlayerSnapshot {
+    id: PlayerId,
+    transform: Transform,
+    velocity: Velocity2D,
+    health: Health,
+    player: Player,
+    invulnerable: Option<Invulnerable>,
+    entry: Option<RespawnEntry>,
+    dying: Option<DyingFade>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct EnemySnapshot {
+    transform: Transform,
+    velocity: Velocity2D,
+    health: Health,
+    archetype: Option<EnemyArchetype>, // 보스는 None
+    gun: Option<EnemyGun>,
+    weave: Option<Weave>,
+    boss: Option<Boss>,
+    dying: Option<DyingFade>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct BulletSnapshot {
+    transform: Transform,
+    velocity: Velocity2D,
+    bullet: Bullet,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct EnemyBulletSnapshot {
+    transform: Transform,
+    velocity: Velocity2D,
+}
+
+impl WorldSnapshot {
+    pub(super) fn to_bytes(&self) -> bincode::Result<Vec<u8>> {
+        bincode::serialize(self)
+    }
+
+    pub(super) fn from_bytes(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes)
+    }
+
+    // 스냅샷 시점의 플레이 시간(초)
+    pub(super) fn elapsed(&self) -> f32 {
+        self.stats.elapsed
+    }
+}
+
+// 현재 월드를 읽어 스냅샷을 만든다
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub(super) struct SnapshotReader<'w, 's> {
+    players: Query<
+        'w,
+        's,
+        (
+            &'static PlayerId,
+            &'static Transform,
+            &'static Velocity2D,
+            &'static Health,
+            &'static Player,
+            Option<&'static Invulnerable>,
+            Option<&'static RespawnEntry>,
+            Option<&'static DyingFade>,
+        ),
+    >,
+    enemies: Query<
+        'w,
+        's,
+        (
+            &'static Transform,
+            &'static Velocity2D,
+            &'static Health,
+            Option<&'static EnemyArchetype>,
+            Option<&'static EnemyGun>,
+            Option<&'static Weave>,
+            Option<&'static Boss>,
+            Option<&'static DyingFade>,
+        ),
+        With<Enemy>,
+    >,
+    bullets: Query<'w, 's, (&'static Transform, &'static Velocity2D, &'static Bullet)>,
+    enemy_bullets: Query<'w, 's, (&'static Transform, &'static Velocity2D), With<EnemyBullet>>,
+    score: Res<'w, Score>,
+    spawner: Option<Res<'w, EnemySpawner>>,
+    lives: Option<Res<'w, Lives>>,
+    respawn_queue: Option<Res<'w, RespawnQueue>>,
+    stats: Res<'w, RunStats>,
+    stage: Option<Res<'w, StageRun>>,
+    continue_prompt: Option<Res<'w, ContinuePrompt>>,
+    difficulty: Res<'w, Difficulty>,
+}
+
+impl SnapshotReader<'_, '_> {
+    // 인게임 리소스가 아직 없으면 None
+    pub(super) fn capture(&self) -> Option<WorldSnapshot> {
+        let (Some(spawner), Some(lives), Some(respawn_queue)) = (&self.spawner, &self.lives, &self.respawn_queue)
+        else {
+            return None;
+        };
+        Some(WorldSnapshot {
+            players: self
+                .players
+                .iter()
+                .map(|(id, tf, velocity, health, player, invulnerable, entry, dying)| PlayerSnapshot {
+                    id: *id,
+                    transform: *tf,
+                    velocity: *velocity,
+                    health: health.clone(),
+                    player: player.clone(),
+                    invulnerable: invulnerable.cloned(),
+                    entry: entry.cloned(),
+                    dying: dying.cloned(),
+                })
+                .collect(),
+            enemies: self
+                .enemies
+                .iter()
+                .map(|(tf, velocity, health, archetype, gun, weave, boss, dying)| EnemySnapshot {
+                    transform: *tf,
+                    velocity: *velocity,
+                    health: health.clone(),
+                    archetype: archetype.copied(),
+                    gun: gun.cloned(),
+                    weave: weave.cloned(),
+                    boss: boss.cloned(),
+                    dying: dying.cloned(),
+                })
+                .collect(),
+            bullets: self
+                .bullets
+                .iter()
+                .map(|(tf, velocity, bullet)| BulletSnapshot {
+                    transform: *tf,
+                    velocity: *velocity,
+                    bullet: bullet.clone(),
+                })
+                .collect(),
+            enemy_bullets: self
+                .enemy_bullets
+                .iter()
+                .map(|(tf, velocity)| EnemyBulletSnapshot {
+                    transform: *tf,
+                    velocity: *velocity,
+                })
+                .collect(),
+            score: *self.score,
+            spawner: (**spawner).clone(),
+            lives: (**lives).clone(),
+            respawn_queue: (**respawn_queue).clone(),
+            stats: *self.stats,
+            stage: self.stage.as_deref().cloned(),
+            continue_prompt: self.continue_prompt.as_deref().cloned(),
+            pressure: self.difficulty.pressure(),
+        })
+    }
+}
+
+// 현재 게임플레이 엔티티를 지우고 스냅샷 상태로 다시 만든다
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub(super) struct SnapshotWriter<'w, 's> {
+    commands: Commands<'w, 's>,
+    frame_cache: Res<'w, FrameCache>,
+    playfield: Res<'w, Playfield>,
+    settings: Res<'w, Settings>,
+    existing: Query<
+        'w,
+        's,
+        Entity,
+        Or<(
+            With<Player>,
+            With<Enemy>,
+            With<Bullet>,
+            With<EnemyBullet>,
+            With<BossHealthBar>,
+            With<ContinueScreen>,
+        )>,
+    >,
+}
+
+impl SnapshotWriter<'_, '_> {
+    pub(super) fn restore(&mut self, snapshot: &WorldSnapshot) {
+        let commands = &mut self.commands;
+        for e in &self.existing {
+            commands.entity(e).despawn_recursive();
+        }
+        let frames = |key: &str| self.frame_cache.map.get(key).expect("Missing frames").clone();
+
+        for p in &snapshot.players {
+            let e = spawn_player(commands, frames("player"), &self.playfield, &self.settings, p.id, false);
+            let mut ecmd = commands.entity(e);
+            ecmd.insert((p.transform, p.velocity, p.health.clone(), p.player.clone()));
+            if let Some(entry) = &p.entry {
+                ecmd.remove::<PlayerMovement>().insert(entry.clone());
+            }
+            if let Some(invulnerable) = &p.invulnerable {
+                ecmd.insert(invulnerable.clone());
+            }
+            if let Some(dying) = &p.dying {
+                ecmd.insert(dying.clone());
+            }
+        }
+
+        for enemy in &snapshot.enemies {
+            let e = match (&enemy.boss, enemy.archetype) {
+                (Some(boss), _) => insert_boss(
+                    commands,
+                    frames("enemy"),
+                    enemy.transform.translation,
+                    enemy.transform.scale,
+                    boss.clone(),
+                    enemy.health.clone(),
+                ),
+                (None, archetype) => spawn_enemy(
+                    commands,
+                    frames("enemy"),
+                    archetype.unwrap_or(EnemyArchetype::Grunt),
+                    enemy.transform.translation.truncate(),
+                    enemy.velocity.x,
+                    enemy.health.max as f32,
+                ),
+            };
+            let mut ecmd = commands.entity(e);
+            ecmd.insert((enemy.transform, enemy.velocity, enemy.health.clone()));
+            if let Some(gun) = &enemy.gun {
+                ecmd.insert(gun.clone());
+            }
+            if let Some(weave) = &enemy.weave {
+                ecmd.insert(weave.clone());
+            }
+            if let Some(dying) = &enemy.dying {
+                ecmd.insert(dying.clone());
+            }
+        }
+
+        for bullet in &snapshot.bullets {
+            let velocity = Vec2::new(bullet.velocity.x, bullet.velocity.y);
+            let e = spawn_player_bullet(
+                commands,
+                frames("bullet"),
+                bullet.transform.translation,
+                velocity,
+                bullet.bullet.owner,
+            );
+            commands.entity(e).insert((bullet.transform, bullet.bullet.clone()));
+        }
+        for bullet in &snapshot.enemy_bullets {
+            let velocity = Vec2::new(bullet.velocity.x, bullet.velocity.y);
+            spawn_enemy_bullet(commands, bullet.transform.translation.truncate(), velocity);
+        }
+
+        commands.insert_resource(snapshot.score);
+        commands.insert_resource(snapshot.spawner.clone());
+        commands.insert_resource(snapshot.lives.clone());
+        commands.insert_resource(snapshot.respawn_queue.clone());
+        commands.insert_resource(snapshot.stats);
+        match &snapshot.stage {
+            Some(stage) => commands.insert_resource(stage.clone()),
+            None => commands.remove_resource::<StageRun>(),
+        }
+        match &snapshot.continue_prompt {
+            Some(prompt) => {
+                commands.insert_resource(prompt.clone());
+                spawn_continue_screen(commands);
+            }
+            None => commands.remove_resource::<ContinuePrompt>(),
+        }
+        let pressure = snapshot.pressure;
+        commands.queue(move |world: &mut World| {
+            world.resource_mut::<Difficulty>().set_pressure(pressure);
+        });
+    }
+}
+
+// 디버그 빌드 전용: F5로 현재 상태를 저장, F9로 저장한 상태를 복원
+pub(super) fn snapshot_hotkey_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    reader: SnapshotReader,
+    mut writer: SnapshotWriter,
+    mut slot: Local<Option<Vec<u8>>>,
+) {
+    if keys.just_pressed(KeyCode::F5) {
+        let Some(snapshot) = reader.capture() else {
+            return;
+        };
+        match snapshot.to_bytes() {
+            Ok(bytes) => {
+                info!(
+                    "Snapshot saved: {} players, {} enemies, {} bytes",
+                    snapshot.players.len(),
+                    snapshot.enemies.len(),
+                    bytes.len()
+                );
+                *slot = Some(bytes);
+            }
+            Err(err) => error!("Failed to encode snapshot: {}", err),
+        }
+    }
+    if keys.just_pressed(KeyCode::F9) {
+        let Some(bytes) = slot.as_deref() else {
+            info!("No snapshot to restore");
+            return;
+        };
+        match WorldSnapshot::from_bytes(bytes) {
+            Ok(snapshot) => {
+                writer.restore(&snapshot);
+                info!("Snapshot restored");
+            }
+            Err(err) => error!("Failed to decode snapshot: {}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(seconds: f32, elapsed: f32, mode: TimerMode) -> Timer {
+        let mut timer = Timer::from_seconds(seconds, mode);
+        timer.tick(std::time::Duration::from_secs_f32(elapsed));
+        timer
+    }
+
+    fn sample_snapshot() -> WorldSnapshot {
+        let mut score = Score::default();
+        score.add(PlayerId::One, 1200);
+        score.add(PlayerId::Two, 300);
+        WorldSnapshot {
+            players: vec![
+                PlayerSnapshot {
+                    id: PlayerId::One,
+                    transform: Transform::from_xyz(-580.0, 90.5, 0.5),
+                    velocity: Velocity2D { x: 0.0, y: 120.25 },
+                    health: Health { current: 2, max: 3 },
+                    player: Player {
+                        fire_timer: timer(0.15, 0.07, TimerMode::Repeating),
+                        weapon: Weapon::Rapid,
+                    },
+                    invulnerable: Some(Invulnerable {
+                        timer: timer(2.5, 1.2, TimerMode::Once),
+                        blink: timer(0.1, 0.03, TimerMode::Repeating),
+                    }),
+                    entry: None,
+                    dying: None,
+                },
+                PlayerSnapshot {
+                    id: PlayerId::Two,
+                    transform: Transform::from_xyz(-700.0, -90.0, 0.5),
+                    velocity: Velocity2D { x: 300.0, y: 0.0 },
+                    health: Health { current: 3, max: 3 },
+                    player: Player {
+                        fire_timer: timer(0.3, 0.0, TimerMode::Repeating),
+                        weapon: Weapon::Spread,
+                    },
+                    invulnerable: None,
+                    entry: Some(RespawnEntry {
+                        target: Vec2::new(-580.0, -90.0),
+                    }),
+                    dying: None,
+                },
+            ],
+            enemies: vec![
+                EnemySnapshot {
+                    transform: Transform::from_xyz(400.0, 120.0, 0.3),
+                    velocity: Velocity2D { x: -360.0, y: 0.0 },
+                    health: Health { current: 4, max: 5 },
+                    archetype: Some(EnemyArchetype::Weaver),
+                    gun: None,
+                    weave: Some(Weave {
+                        base_y: 120.0,
+                        phase: 1.75,
+                        amplitude: 40.0,
+                    }),
+                    boss: None,
+                    dying: None,
+                },
+                EnemySnapshot {
+                    transform: Transform::from_xyz(520.0, 0.0, 0.3),
+                    velocity: Velocity2D { x: -80.0, y: 30.0 },
+                    health: Health { current: 180, max: 200 },
+                    archetype: None,
+                    gun: Some(EnemyGun {
+                        timer: timer(1.2, 0.4, TimerMode::Repeating),
+                    }),
+                    weave: None,
+                    boss: Some(Boss {
+                        target_x: 420.0,
+                        phase: 0.5,
+                        spread: 5,
+                        fire_timer: timer(0.8, 0.6, TimerMode::Repeating),
+                    }),
+                    dying: Some(DyingFade {
+                        timer: timer(0.4, 0.1, TimerMode::Once),
+                    }),
+                },
+            ],
+            bullets: vec![BulletSnapshot {
+                transform: Transform::from_xyz(-200.0, 90.0, 0.4),
+                velocity: Velocity2D { x: 900.0, y: 0.0 },
+                bullet: Bullet {
+                    life: timer(2.0, 0.3, TimerMode::Once),
+                    owner: PlayerId::One,
+                },
+            }],
+            enemy_bullets: vec![EnemyBulletSnapshot {
+                transform: Transform::from_xyz(100.0, -40.0, 0.4),
+                velocity: Velocity2D { x: -200.0, y: -160.0 },
+            }],
+            score,
+            spawner: EnemySpawner {
+                columns: 17,
+                remaining: Some(6),
+                health_scale: 1.4,
+                mix: vec![(EnemyArchetype::Grunt, 5), (EnemyArchetype::Tank, 1)],
+                ..Default::default()
+            },
+            lives: Lives::new(2, false),
+            respawn_queue: RespawnQueue(vec![(PlayerId::Two, timer(1.5, 0.5, TimerMode::Once))]),
+            stats: RunStats {
+                kills: 23,
+                shots_fired: 410,
+                shots_hit: 190,
+                damage_taken: 2,
+                elapsed: 95.3,
+            },
+            stage: Some(StageRun {
+                stage: 1,
+                wave: 3,
+                pause: timer(2.0, 0.5, TimerMode::Once),
+                phase: StagePhase::Cleared(timer(3.0, 1.0, TimerMode::Once)),
+            }),
+            continue_prompt: Some(ContinuePrompt {
+                countdown: timer(CONTINUE_SECONDS, 4.2, TimerMode::Once),
+            }),
+            pressure: 0.65,
+        }
+    }
+
+    #[test]
+    fn round_trip_is_byte_identical() {
+        let bytes = sample_snapshot().to_bytes().expect("encode");
+        let decoded = WorldSnapshot::from_bytes(&bytes).expect("decode");
+        assert_eq!(decoded.to_bytes().expect("re-encode"), bytes);
+        assert_eq!(decoded.players.len(), 2);
+        assert_eq!(decoded.enemies.len(), 2);
+        assert_eq!(decoded.elapsed(), 95.3);
+    }
+}
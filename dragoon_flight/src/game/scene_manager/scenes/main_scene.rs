@@ -1,6 +1,13 @@
 use bevy::{app::AppExit, ecs::schedule::SystemConfigs, prelude::*};
 
-use super::super::{scene_states::SceneStatus, scene_traits::IScene};
+use super::super::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStatus};
+use crate::game::build_info::BuildInfo;
+use crate::game::difficulty::Difficulty;
+use crate::game::fonts::registry::FontRole;
+use crate::game::locale::text::Localized;
+use crate::game::run::RunMode;
+use crate::game::settings::{CoopMode, MovementPreset, Settings};
 
 // --- Constants ---
 const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
@@ -8,21 +15,32 @@ const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
 const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
 
 // --- Marker Components ---
-#[derive(Component)]
-struct OnMainMenuScreen;
-
-#[derive(Component)]
-struct StartGameButton;
+// 메인 메뉴 버튼 종류. 옵션 버튼은 누를 때마다 값이 순환한다
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum MenuButton {
+    StartGame,
+    Campaign,
+    Survival,
+    Practice,
+    Difficulty,
+    Adaptive,
+    Movement,
+    Players,
+    SharedLives,
+    Language,
+    Exit,
+}
 
+// 버튼 라벨 텍스트. 옵션 값이 바뀌면 다시 그린다
 #[derive(Component)]
-struct ExitButton;
+struct MenuButtonText(MenuButton);
 
 // --- Scene Definition ---
 pub struct MainScene {}
 
 impl IScene for MainScene {
     fn state(&self) -> SceneStatus {
-        SceneStatus::Main
+        scene_ids::MAIN
     }
 
     fn system_on_enter(&self) -> SystemConfigs {
@@ -33,35 +51,37 @@ impl IScene for MainScene {
         main_menu_interaction.into_configs()
     }
 
-    fn system_on_exit(&self) -> SystemConfigs {
-        despawn_screen::<OnMainMenuScreen>.into_configs()
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::IN_GAME, scene_ids::STAGE_SELECT, scene_ids::PRACTICE_SELECT]
     }
 }
 
 // --- Systems ---
-fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_main_menu(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    difficulty: Res<Difficulty>,
+    build: Res<BuildInfo>,
+) {
     // 2D 카메라
-    commands.spawn((Camera2d, OnMainMenuScreen));
+    commands.spawn(Camera2d);
 
     // 버튼 레이아웃(Node) 공통 스타일
     let button_node = Node {
-        width: Val::Px(250.0),
-        height: Val::Px(65.0),
-        margin: UiRect::all(Val::Px(20.0)),
+        width: Val::Px(360.0),
+        height: Val::Px(48.0),
+        margin: UiRect::all(Val::Px(4.0)),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         ..Default::default()
     };
 
-    // 텍스트 공통 스타일 (프로젝트 폰트 사용)
+    // 텍스트 공통 스타일 (글꼴은 언어별 글꼴 체인에서 정해진다)
     let text_color = TextColor(Color::srgb(0.9, 0.9, 0.9));
-    let _font_regular: Handle<Font> = asset_server.load("fonts/NanumGothic.ttf");
-    let font_bold: Handle<Font> = asset_server.load("fonts/NanumGothicBold.ttf");
 
     // 루트 컨테이너
     commands
         .spawn((
-            OnMainMenuScreen,
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -72,54 +92,135 @@ fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
             },
         ))
         .with_children(|parent| {
-            // Start Game 버튼
-            parent
-                .spawn((
-                    Button,
-                    StartGameButton,
-                    button_node.clone(),
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::new("Start Game"),
-                        TextFont { font: font_bold.clone(), font_size: 40.0, ..Default::default() },
-                        text_color,
-                    ));
-                });
-
-            // Exit 버튼
-            parent
-                .spawn((
-                    Button,
-                    ExitButton,
-                    button_node,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::new("Exit"),
-                        TextFont { font: font_bold, font_size: 40.0, ..Default::default() },
-                        TextColor(Color::srgb(0.9, 0.9, 0.9)),
-                    ));
-                });
+            for button in [
+                MenuButton::StartGame,
+                MenuButton::Campaign,
+                MenuButton::Survival,
+                MenuButton::Practice,
+                MenuButton::Difficulty,
+                MenuButton::Adaptive,
+                MenuButton::Movement,
+                MenuButton::Players,
+                MenuButton::SharedLives,
+                MenuButton::Language,
+                MenuButton::Exit,
+            ] {
+                // 옵션 버튼은 라벨이 길어 글자를 조금 작게
+                let font_size = match button {
+                    MenuButton::StartGame
+                    | MenuButton::Campaign
+                    | MenuButton::Survival
+                    | MenuButton::Practice
+                    | MenuButton::Exit => 36.0,
+                    _ => 28.0,
+                };
+                parent
+                    .spawn((
+                        Button,
+                        button,
+                        button_node.clone(),
+                        BackgroundColor(NORMAL_BUTTON),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            menu_label(button, &settings, &difficulty),
+                            FontRole::Title,
+                            TextFont { font_size, ..Default::default() },
+                            text_color,
+                            MenuButtonText(button),
+                        ));
+                    });
+            }
         });
+
+    // 오른쪽 아래 빌드 정보 (버그 리포트용)
+    commands.spawn((
+        Text::new(build.to_string()),
+        TextFont { font_size: 14.0, ..Default::default() },
+        TextColor(Color::srgba(0.8, 0.8, 0.8, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(8.0),
+            bottom: Val::Px(6.0),
+            ..Default::default()
+        },
+    ));
+}
+
+fn menu_label(button: MenuButton, settings: &Settings, difficulty: &Difficulty) -> Localized {
+    let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+    match button {
+        MenuButton::StartGame => Localized::new("menu-start"),
+        MenuButton::Campaign => Localized::new("menu-campaign"),
+        MenuButton::Survival => Localized::new("menu-survival"),
+        MenuButton::Practice => Localized::new("menu-practice"),
+        MenuButton::Difficulty => {
+            Localized::new("menu-difficulty").with("level", difficulty.level.label().to_lowercase())
+        }
+        MenuButton::Adaptive => Localized::new("menu-adaptive").with("enabled", on_off(difficulty.adaptive)),
+        MenuButton::Movement => Localized::new("menu-movement").with(
+            "preset",
+            match settings.gameplay.movement {
+                MovementPreset::Free => "free",
+                MovementPreset::VerticalOnly => "vertical",
+            },
+        ),
+        MenuButton::Players => Localized::new("menu-players").with(
+            "mode",
+            match settings.gameplay.coop {
+                CoopMode::Solo => "solo",
+                CoopMode::KeyboardSplit => "keyboard",
+                CoopMode::Gamepads => "gamepads",
+            },
+        ),
+        MenuButton::SharedLives => {
+            Localized::new("menu-shared-lives").with("enabled", on_off(settings.gameplay.shared_lives))
+        }
+        MenuButton::Language => Localized::new("menu-language").with("language", settings.language.code()),
+        MenuButton::Exit => Localized::new("menu-exit"),
+    }
 }
 
 fn main_menu_interaction(
-    mut param_set: ParamSet<(
-        Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<StartGameButton>)>, 
-        Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<ExitButton>)>, 
-    )>,
+    mut buttons: Query<(&Interaction, &MenuButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut labels: Query<(&MenuButtonText, &mut Localized)>,
     mut app_exit_events: EventWriter<AppExit>,
     mut next_state: ResMut<NextState<SceneStatus>>,
+    mut settings: ResMut<Settings>,
+    mut difficulty: ResMut<Difficulty>,
+    mut run_mode: ResMut<RunMode>,
 ) {
-    // Handle Start Game button
-    for (interaction, mut color) in param_set.p0().iter_mut() {
+    let mut options_changed = false;
+    for (interaction, button, mut color) in &mut buttons {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
-                next_state.set(SceneStatus::InGame);
+                match button {
+                    MenuButton::StartGame => {
+                        *run_mode = RunMode::Classic;
+                        next_state.set(scene_ids::IN_GAME);
+                    }
+                    MenuButton::Campaign => next_state.set(scene_ids::STAGE_SELECT),
+                    MenuButton::Survival => {
+                        *run_mode = RunMode::Survival;
+                        next_state.set(scene_ids::IN_GAME);
+                    }
+                    MenuButton::Practice => next_state.set(scene_ids::PRACTICE_SELECT),
+                    MenuButton::Difficulty => difficulty.level = difficulty.level.next(),
+                    MenuButton::Adaptive => difficulty.adaptive = !difficulty.adaptive,
+                    MenuButton::Movement => {
+                        settings.gameplay.movement = settings.gameplay.movement.next()
+                    }
+                    MenuButton::Players => settings.gameplay.coop = settings.gameplay.coop.next(),
+                    MenuButton::SharedLives => {
+                        settings.gameplay.shared_lives = !settings.gameplay.shared_lives
+                    }
+                    MenuButton::Language => settings.language = settings.language.next(),
+                    MenuButton::Exit => {
+                        app_exit_events.send(AppExit::Success);
+                    }
+                }
+                options_changed = true;
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
@@ -130,26 +231,9 @@ fn main_menu_interaction(
         }
     }
 
-    // Handle Exit button
-    for (interaction, mut color) in param_set.p1().iter_mut() {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = PRESSED_BUTTON.into();
-                app_exit_events.send(AppExit::Success);
-            }
-            Interaction::Hovered => {
-                *color = HOVERED_BUTTON.into();
-            }
-            Interaction::None => {
-                *color = NORMAL_BUTTON.into();
-            }
+    if options_changed {
+        for (label, mut localized) in &mut labels {
+            localized.set_if_neq(menu_label(label.0, &settings, &difficulty));
         }
     }
 }
-
-// Generic system to despawn all entities with a given component
-fn despawn_screen<T: Component>(to_despawn: Query<Entity, With<T>>, mut commands: Commands) {
-    for entity in &to_despawn {
-        commands.entity(entity).despawn_recursive();
-    }
-}
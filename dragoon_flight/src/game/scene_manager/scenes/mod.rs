@@ -1,3 +1,7 @@
 pub mod ingame_scene;
 pub mod game_over_scene;
 pub mod main_scene;
+pub mod pause_scene;
+pub mod practice_select_scene;
+pub mod stage_clear_scene;
+pub mod stage_select_scene;
@@ -0,0 +1,82 @@
+use bevy::{ecs::schedule::SystemConfigs, prelude::*};
+
+use super::super::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStack, SceneStatus};
+use crate::game::fonts::registry::FontRole;
+use crate::game::locale::text::Localized;
+
+// InGame 위에 쌓이는 일시정지 화면. 아래 씬의 카메라로 그려지므로 카메라를 만들지 않는다
+pub struct PauseScene;
+
+impl IScene for PauseScene {
+    fn state(&self) -> SceneStatus {
+        scene_ids::PAUSE
+    }
+
+    fn system_on_enter(&self) -> SystemConfigs {
+        setup_pause.into_configs()
+    }
+
+    fn system_on_update(&self) -> SystemConfigs {
+        pause_interaction.into_configs()
+    }
+
+    fn system_on_exit(&self) -> Option<SystemConfigs> {
+        Some(resume_time.into_configs())
+    }
+
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::MAIN]
+    }
+}
+
+// 씬 밖에서 도는 연출(파티클, 카메라 효과)도 멈추도록 게임 시간을 정지
+fn setup_pause(mut commands: Commands, mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(24.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            // 인게임 HUD보다 위, 디버그 오버레이보다 아래
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Localized::new("pause-title"),
+                FontRole::Title,
+                TextFont { font_size: 64.0, ..Default::default() },
+                TextColor(Color::srgb(0.95, 0.95, 0.95)),
+            ));
+            parent.spawn((
+                Localized::new("pause-help"),
+                TextFont { font_size: 24.0, ..Default::default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn resume_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+fn pause_interaction(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut stack: ResMut<SceneStack>,
+    mut next: ResMut<NextState<SceneStatus>>,
+) {
+    if keys.any_just_pressed([KeyCode::KeyP, KeyCode::Escape]) {
+        stack.pop();
+    } else if keys.just_pressed(KeyCode::KeyQ) {
+        // 스택 밖의 씬으로 가면 덮여 있던 InGame도 함께 정리된다
+        next.set(scene_ids::MAIN);
+    }
+}
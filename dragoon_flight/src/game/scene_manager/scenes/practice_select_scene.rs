@@ -0,0 +1,135 @@
+use bevy::{ecs::schedule::SystemConfigs, prelude::*};
+
+use super::super::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStatus};
+use crate::game::fonts::registry::FontRole;
+use crate::game::locale::text::Localized;
+use crate::game::run::RunMode;
+use crate::game::survival::{curve::SurvivalCurve, scores};
+
+// --- Constants ---
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
+
+// --- Marker Components ---
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+enum PracticeButton {
+    Start(f32), // 시작 시각(초)
+    Back,
+}
+
+// --- Scene Definition ---
+pub struct PracticeSelectScene;
+
+impl IScene for PracticeSelectScene {
+    fn state(&self) -> SceneStatus {
+        scene_ids::PRACTICE_SELECT
+    }
+
+    fn system_on_enter(&self) -> SystemConfigs {
+        setup_practice_select.into_configs()
+    }
+
+    fn system_on_update(&self) -> SystemConfigs {
+        practice_select_interaction.into_configs()
+    }
+
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::MAIN, scene_ids::IN_GAME]
+    }
+}
+
+// --- Systems ---
+fn setup_practice_select(mut commands: Commands, curve: Res<SurvivalCurve>) {
+    commands.spawn(Camera2d);
+
+    let button_node = Node {
+        width: Val::Px(420.0),
+        height: Val::Px(48.0),
+        margin: UiRect::all(Val::Px(4.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Localized::new("practice-title"),
+                FontRole::Title,
+                TextFont { font_size: 48.0, ..Default::default() },
+                TextColor(Color::srgb(0.95, 0.95, 0.95)),
+                Node { margin: UiRect::bottom(Val::Px(8.0)), ..Default::default() },
+            ));
+            parent.spawn((
+                Localized::new("practice-help"),
+                TextFont { font_size: 20.0, ..Default::default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                Node { margin: UiRect::bottom(Val::Px(16.0)), ..Default::default() },
+            ));
+
+            for time in curve.sections() {
+                let joining = curve.joining_at(time);
+                let label = if joining.is_empty() {
+                    scores::format_time(time)
+                } else {
+                    let names: Vec<String> = joining.iter().map(|a| format!("{:?}", a)).collect();
+                    format!("{}  (+{})", scores::format_time(time), names.join(", "))
+                };
+                spawn_button(parent, PracticeButton::Start(time), &button_node, Text::new(label), 28.0);
+            }
+            spawn_button(parent, PracticeButton::Back, &button_node, Localized::new("common-back"), 32.0);
+        });
+}
+
+// 구간 시간 라벨은 번역하지 않으므로 Text, 나머지는 Localized를 받는다
+fn spawn_button(parent: &mut ChildBuilder, button: PracticeButton, node: &Node, label: impl Bundle, font_size: f32) {
+    parent
+        .spawn((Button, button, node.clone(), BackgroundColor(NORMAL_BUTTON)))
+        .with_children(|parent| {
+            parent.spawn((
+                label,
+                TextFont { font_size, ..Default::default() },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+        });
+}
+
+fn practice_select_interaction(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut buttons: Query<(&Interaction, &PracticeButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut run_mode: ResMut<RunMode>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(scene_ids::MAIN);
+        return;
+    }
+    for (interaction, button, mut color) in &mut buttons {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                match *button {
+                    PracticeButton::Start(start) => {
+                        *run_mode = RunMode::Practice { start };
+                        next_state.set(scene_ids::IN_GAME);
+                    }
+                    PracticeButton::Back => next_state.set(scene_ids::MAIN),
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
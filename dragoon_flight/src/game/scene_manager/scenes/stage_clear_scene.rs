@@ -0,0 +1,123 @@
+use bevy::{ecs::schedule::SystemConfigs, prelude::*};
+
+use super::super::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStatus};
+use crate::game::campaign::stages::Campaign;
+use crate::game::fonts::registry::FontRole;
+use crate::game::locale::text::Localized;
+use crate::game::run::{RunMode, StageResult};
+use crate::game::survival::scores;
+
+pub struct StageClearScene;
+
+impl IScene for StageClearScene {
+    fn state(&self) -> SceneStatus {
+        scene_ids::STAGE_CLEAR
+    }
+
+    fn system_on_enter(&self) -> SystemConfigs {
+        setup_stage_clear.into_configs()
+    }
+
+    fn system_on_update(&self) -> SystemConfigs {
+        stage_clear_interaction.into_configs()
+    }
+
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::IN_GAME, scene_ids::STAGE_SELECT]
+    }
+}
+
+// 클리어 결과(처치 수, 명중률, 피격 횟수, 시간) 표시
+fn setup_stage_clear(mut commands: Commands, result: Option<Res<StageResult>>, campaign: Res<Campaign>) {
+    commands.spawn(Camera2d);
+
+    let Some(result) = result else {
+        warn!("Stage clear screen entered without a result");
+        return;
+    };
+    let stats = &result.stats;
+    let body = Localized::new("stage-clear-stats")
+        .with("kills", stats.kills)
+        .with("accuracy", format!("{:.1}", stats.accuracy() * 100.0))
+        .with("damage", stats.damage_taken)
+        .with("time", scores::format_time(stats.elapsed))
+        .with("score", format!("{:06}", result.score));
+    let has_next = campaign.stage(result.stage + 1).is_some();
+    let hint = if has_next {
+        "stage-clear-hint-next"
+    } else {
+        "stage-clear-hint-complete"
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(24.0),
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                stage_title(&campaign, result.stage),
+                FontRole::Title,
+                TextFont { font_size: 52.0, ..Default::default() },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+            ));
+            parent.spawn((
+                body,
+                TextFont { font_size: 30.0, ..Default::default() },
+                TextColor(Color::srgb(0.95, 0.95, 0.95)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+            if result.unlocked_next {
+                parent.spawn((
+                    Localized::new("stage-clear-unlocked"),
+                    TextFont { font_size: 30.0, ..Default::default() },
+                    TextColor(Color::srgb(0.95, 0.95, 0.95)),
+                ));
+            }
+            parent.spawn((
+                Localized::new(hint),
+                TextFont { font_size: 24.0, ..Default::default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+// 스테이지 번호와 (번역된) 이름. 목록에 없는 스테이지면 이름을 비움
+fn stage_title(campaign: &Campaign, stage: usize) -> Localized {
+    let title = Localized::new("stage-clear-title").with("number", stage + 1);
+    match campaign.stage(stage) {
+        Some(def) => title.with_key("name", def.name_key),
+        None => title.with("name", ""),
+    }
+}
+
+fn stage_clear_interaction(
+    keys: Res<ButtonInput<KeyCode>>,
+    result: Option<Res<StageResult>>,
+    campaign: Res<Campaign>,
+    mut run_mode: ResMut<RunMode>,
+    mut next: ResMut<NextState<SceneStatus>>,
+) {
+    let next_stage = result
+        .map(|result| result.stage + 1)
+        .filter(|&stage| campaign.stage(stage).is_some());
+    if keys.just_pressed(KeyCode::Enter) {
+        match next_stage {
+            Some(stage) => {
+                *run_mode = RunMode::Campaign { stage };
+                next.set(scene_ids::IN_GAME);
+            }
+            None => next.set(scene_ids::STAGE_SELECT),
+        }
+    } else if keys.just_pressed(KeyCode::Escape) {
+        next.set(scene_ids::STAGE_SELECT);
+    }
+}
@@ -0,0 +1,161 @@
+use bevy::{ecs::schedule::SystemConfigs, prelude::*};
+
+use super::super::scene_ids;
+use dragoon_engine::scene::{IScene, SceneStatus};
+use crate::game::campaign::{progress::CampaignProgress, stages::Campaign};
+use crate::game::fonts::registry::FontRole;
+use crate::game::locale::text::Localized;
+use crate::game::run::RunMode;
+
+// --- Constants ---
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
+const LOCKED_BUTTON: Color = Color::srgb(0.08, 0.08, 0.08);
+
+// --- Marker Components ---
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum StageButton {
+    Stage(usize),
+    Back,
+}
+
+// 잠긴 스테이지 버튼 (눌러도 반응 없음)
+#[derive(Component)]
+struct Locked;
+
+// --- Scene Definition ---
+pub struct StageSelectScene;
+
+impl IScene for StageSelectScene {
+    fn state(&self) -> SceneStatus {
+        scene_ids::STAGE_SELECT
+    }
+
+    fn system_on_enter(&self) -> SystemConfigs {
+        setup_stage_select.into_configs()
+    }
+
+    fn system_on_update(&self) -> SystemConfigs {
+        stage_select_interaction.into_configs()
+    }
+
+    fn transitions(&self) -> Vec<SceneStatus> {
+        vec![scene_ids::MAIN, scene_ids::IN_GAME]
+    }
+}
+
+// --- Systems ---
+fn setup_stage_select(
+    mut commands: Commands,
+    campaign: Res<Campaign>,
+    progress: Res<CampaignProgress>,
+) {
+    commands.spawn(Camera2d);
+
+    let button_node = Node {
+        width: Val::Px(420.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(10.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Localized::new("stage-select-title"),
+                FontRole::Title,
+                TextFont { font_size: 48.0, ..Default::default() },
+                TextColor(Color::srgb(0.95, 0.95, 0.95)),
+                Node { margin: UiRect::bottom(Val::Px(24.0)), ..Default::default() },
+            ));
+
+            for (index, stage) in campaign.stages.iter().enumerate() {
+                let unlocked = progress.is_unlocked(index);
+                let label = if !unlocked {
+                    Localized::new("stage-locked").with("number", index + 1)
+                } else if progress.best_score(index) > 0 {
+                    Localized::new("stage-entry-best")
+                        .with("number", index + 1)
+                        .with_key("name", stage.name_key)
+                        .with("best", format!("{:06}", progress.best_score(index)))
+                } else {
+                    Localized::new("stage-entry").with("number", index + 1).with_key("name", stage.name_key)
+                };
+                let mut button = parent.spawn((
+                    Button,
+                    StageButton::Stage(index),
+                    button_node.clone(),
+                    BackgroundColor(if unlocked { NORMAL_BUTTON } else { LOCKED_BUTTON }),
+                ));
+                if !unlocked {
+                    button.insert(Locked);
+                }
+                button.with_children(|parent| {
+                    parent.spawn((
+                        label,
+                        TextFont { font_size: 28.0, ..Default::default() },
+                        TextColor(if unlocked {
+                            Color::srgb(0.9, 0.9, 0.9)
+                        } else {
+                            Color::srgb(0.4, 0.4, 0.4)
+                        }),
+                    ));
+                });
+            }
+
+            parent
+                .spawn((Button, StageButton::Back, button_node.clone(), BackgroundColor(NORMAL_BUTTON)))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Localized::new("common-back"),
+                        TextFont { font_size: 32.0, ..Default::default() },
+                        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                    ));
+                });
+        });
+}
+
+#[allow(clippy::type_complexity)]
+fn stage_select_interaction(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut buttons: Query<
+        (&Interaction, &StageButton, &mut BackgroundColor),
+        (Changed<Interaction>, Without<Locked>),
+    >,
+    mut run_mode: ResMut<RunMode>,
+    mut next_state: ResMut<NextState<SceneStatus>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(scene_ids::MAIN);
+        return;
+    }
+    for (interaction, button, mut color) in &mut buttons {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                match *button {
+                    StageButton::Stage(stage) => {
+                        *run_mode = RunMode::Campaign { stage };
+                        next_state.set(scene_ids::IN_GAME);
+                    }
+                    StageButton::Back => next_state.set(scene_ids::MAIN),
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
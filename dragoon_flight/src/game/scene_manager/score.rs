@@ -1,7 +1,21 @@
 use bevy::ecs::system::Resource;
+use serde::{Deserialize, Serialize};
 
-#[derive(Resource,Default, Debug, Clone, Copy)]
+use crate::game::input::{MAX_PLAYERS, PlayerId};
+
+#[derive(Resource,Default, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Score {
-    pub value: u32,
+    pub value: u32,                     // 전체 합계
+    pub per_player: [u32; MAX_PLAYERS], // 플레이어별 점수
 }
 
+impl Score {
+    pub fn add(&mut self, player: PlayerId, points: u32) {
+        self.value += points;
+        self.per_player[player.index()] += points;
+    }
+
+    pub fn of(&self, player: PlayerId) -> u32 {
+        self.per_player[player.index()]
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+
+use crate::game::locale::language::Language;
+
+// 사용자 설정 (볼륨 등). 값 변경 시 관련 시스템이 즉시 반영한다.
+#[derive(Resource, Debug, Clone)]
+pub struct Settings {
+    pub audio: AudioSettings,
+    pub camera: CameraEffectSettings,
+    pub gameplay: GameplaySettings,
+    pub language: Language, // UI 언어
+    pub debug: DebugSettings,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AudioSettings {
+    pub master_volume: f32, // 전체 볼륨 버스 (0.0 ~ 1.0)
+    pub music_volume: f32,  // 배경음악 버스
+    pub sfx_volume: f32,    // 효과음 버스
+}
+
+impl AudioSettings {
+    pub fn music_gain(&self) -> f32 {
+        (self.master_volume * self.music_volume).clamp(0.0, 1.0)
+    }
+
+    pub fn sfx_gain(&self) -> f32 {
+        (self.master_volume * self.sfx_volume).clamp(0.0, 1.0)
+    }
+}
+
+// 화면 흔들림/히트스톱/플래시. 접근성을 위해 개별적으로 끌 수 있다.
+#[derive(Debug, Clone, Copy)]
+pub struct CameraEffectSettings {
+    pub screen_shake: bool,
+    pub shake_intensity: f32, // 흔들림 배율 (0.0 ~ 1.0)
+    pub hit_stop: bool,
+    pub flashes: bool,
+    pub flash_intensity: f32, // 플래시 불투명도 배율 (0.0 ~ 1.0)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GameplaySettings {
+    pub movement: MovementPreset,
+    pub coop: CoopMode,
+    pub shared_lives: bool, // 2인 플레이 시 목숨을 함께 씀
+}
+
+// 개발용 설정
+#[derive(Debug, Clone)]
+pub struct DebugSettings {
+    // 로그 필터 (EnvFilter 문법). 명중/생성 같은 이벤트별 로그는 debug/trace 수준이라 기본값에서는 숨겨진다
+    pub log_filter: String,
+}
+
+// DRAGOON_LOG 환경 변수가 있으면 기본 로그 필터 대신 사용
+const LOG_FILTER_ENV: &str = "DRAGOON_LOG";
+const DEFAULT_LOG_FILTER: &str = "info,wgpu=error,naga=warn";
+
+impl Default for DebugSettings {
+    fn default() -> Self {
+        Self {
+            log_filter: std::env::var(LOG_FILTER_ENV).unwrap_or_else(|_| DEFAULT_LOG_FILTER.to_string()),
+        }
+    }
+}
+
+// 로컬 2인 플레이 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoopMode {
+    #[default]
+    Solo,
+    KeyboardSplit, // 1P는 WASD, 2P는 방향키
+    Gamepads,      // 게임패드 두 개 (1P는 키보드도 사용 가능)
+}
+
+impl CoopMode {
+    pub fn next(self) -> Self {
+        match self {
+            CoopMode::Solo => CoopMode::KeyboardSplit,
+            CoopMode::KeyboardSplit => CoopMode::Gamepads,
+            CoopMode::Gamepads => CoopMode::Solo,
+        }
+    }
+
+    pub fn player_count(self) -> usize {
+        match self {
+            CoopMode::Solo => 1,
+            CoopMode::KeyboardSplit | CoopMode::Gamepads => 2,
+        }
+    }
+}
+
+// 플레이어 이동 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MovementPreset {
+    #[default]
+    Free, // 플레이필드 안 자유 이동
+    VerticalOnly, // 왼쪽 끝 고정, 상하 이동만
+}
+
+impl MovementPreset {
+    pub fn next(self) -> Self {
+        match self {
+            MovementPreset::Free => MovementPreset::VerticalOnly,
+            MovementPreset::VerticalOnly => MovementPreset::Free,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            audio: AudioSettings {
+                master_volume: 0.8,
+                music_volume: 0.6,
+                sfx_volume: 0.8,
+            },
+            camera: CameraEffectSettings {
+                screen_shake: true,
+                shake_intensity: 1.0,
+                hit_stop: true,
+                flashes: true,
+                flash_intensity: 1.0,
+            },
+            gameplay: GameplaySettings {
+                movement: MovementPreset::default(),
+                coop: CoopMode::default(),
+                shared_lives: false,
+            },
+            language: Language::default(),
+            debug: DebugSettings::default(),
+        }
+    }
+}
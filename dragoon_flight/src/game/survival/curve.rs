@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// 적 종류. 종류마다 체력/속도/크기/점수가 다르다
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EnemyArchetype {
+    Grunt,  // 기본 적
+    Gunner, // 플레이어를 조준해 사격
+    Weaver, // 위아래로 흔들며 접근
+    Tank,   // 느리지만 단단하고 큼
+}
+
+impl EnemyArchetype {
+    pub fn health_factor(self) -> f32 {
+        match self {
+            EnemyArchetype::Grunt | EnemyArchetype::Gunner => 1.0,
+            EnemyArchetype::Weaver => 0.8,
+            EnemyArchetype::Tank => 3.0,
+        }
+    }
+
+    pub fn speed_factor(self) -> f32 {
+        match self {
+            EnemyArchetype::Grunt | EnemyArchetype::Gunner => 1.0,
+            EnemyArchetype::Weaver => 1.1,
+            EnemyArchetype::Tank => 0.6,
+        }
+    }
+
+    pub fn scale(self) -> f32 {
+        match self {
+            EnemyArchetype::Tank => 1.35,
+            _ => 1.0,
+        }
+    }
+
+    // 처치 점수 배율
+    pub fn score_factor(self) -> f32 {
+        match self {
+            EnemyArchetype::Grunt => 1.0,
+            EnemyArchetype::Gunner | EnemyArchetype::Weaver => 1.5,
+            EnemyArchetype::Tank => 3.0,
+        }
+    }
+}
+
+// 곡선의 한 점. 두 점 사이는 선형 보간
+#[derive(Debug, Clone, Copy)]
+pub struct CurvePoint {
+    pub time: f32,         // 생존 시간(초)
+    pub speed: f32,        // 적 이동 속도(px/s)
+    pub col_spacing: f32,  // 열 사이 가로 간격(px). 작을수록 촘촘
+    pub health_scale: f32, // 적 체력 배율
+}
+
+// 일정 시간이 지나면 등장하기 시작하는 적 종류와 가중치
+#[derive(Debug, Clone, Copy)]
+pub struct ArchetypeEntry {
+    pub archetype: EnemyArchetype,
+    pub from: f32,
+    pub weight: u32,
+}
+
+// 생존 모드 난이도 곡선. 리소스를 교체해 조정할 수 있다
+#[derive(Resource, Debug, Clone)]
+pub struct SurvivalCurve {
+    pub points: Vec<CurvePoint>, // time 오름차순
+    pub archetypes: Vec<ArchetypeEntry>,
+}
+
+impl Default for SurvivalCurve {
+    fn default() -> Self {
+        let point = |time, speed, col_spacing, health_scale| CurvePoint {
+            time,
+            speed,
+            col_spacing,
+            health_scale,
+        };
+        let entry = |archetype, from, weight| ArchetypeEntry { archetype, from, weight };
+        Self {
+            points: vec![
+                point(0.0, 280.0, 340.0, 0.6),
+                point(60.0, 340.0, 280.0, 1.0),
+                point(180.0, 420.0, 230.0, 1.4),
+                point(360.0, 520.0, 190.0, 2.0),
+                point(600.0, 600.0, 160.0, 2.8),
+            ],
+            archetypes: vec![
+                entry(EnemyArchetype::Grunt, 0.0, 10),
+                entry(EnemyArchetype::Gunner, 30.0, 3),
+                entry(EnemyArchetype::Weaver, 75.0, 4),
+                entry(EnemyArchetype::Tank, 150.0, 2),
+            ],
+        }
+    }
+}
+
+impl SurvivalCurve {
+    // 경과 시간에 해당하는 값. 마지막 점 이후로는 마지막 값을 유지
+    pub fn sample(&self, time: f32) -> CurvePoint {
+        let Some(first) = self.points.first() else {
+            return CurvePoint {
+                time,
+                speed: 360.0,
+                col_spacing: 240.0,
+                health_scale: 1.0,
+            };
+        };
+        let next = self.points.iter().position(|p| p.time > time);
+        match next {
+            None => *self.points.last().unwrap_or(first),
+            Some(0) => *first,
+            Some(i) => {
+                let (a, b) = (self.points[i - 1], self.points[i]);
+                let t = ((time - a.time) / (b.time - a.time)).clamp(0.0, 1.0);
+                CurvePoint {
+                    time,
+                    speed: a.speed.lerp(b.speed, t),
+                    col_spacing: a.col_spacing.lerp(b.col_spacing, t),
+                    health_scale: a.health_scale.lerp(b.health_scale, t),
+                }
+            }
+        }
+    }
+
+    // 연습 모드 시작 지점 후보: 곡선의 점과 새 적 종류가 등장하는 시각 (오름차순, 중복 제거)
+    pub fn sections(&self) -> Vec<f32> {
+        let mut times: Vec<f32> = self
+            .points
+            .iter()
+            .map(|p| p.time)
+            .chain(self.archetypes.iter().map(|entry| entry.from))
+            .collect();
+        times.sort_by(f32::total_cmp);
+        times.dedup();
+        times
+    }
+
+    // time초에 새로 등장하는 적 종류
+    pub fn joining_at(&self, time: f32) -> Vec<EnemyArchetype> {
+        self.archetypes
+            .iter()
+            .filter(|entry| entry.from == time && time > 0.0)
+            .map(|entry| entry.archetype)
+            .collect()
+    }
+
+    // 경과 시간까지 등장한 적 종류와 가중치
+    pub fn mix_at(&self, time: f32) -> Vec<(EnemyArchetype, u32)> {
+        self.archetypes
+            .iter()
+            .filter(|entry| entry.from <= time && entry.weight > 0)
+            .map(|entry| (entry.archetype, entry.weight))
+            .collect()
+    }
+}
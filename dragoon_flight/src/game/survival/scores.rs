@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::save;
+
+const SCORES_FILE: &str = "survival_scores.json";
+const MAX_ENTRIES: usize = 10;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SurvivalEntry {
+    pub time: f32, // 생존 시간(초)
+    pub score: u32,
+}
+
+// 생존 모드 기록판. 생존 시간 우선, 같으면 점수 순으로 정렬
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct SurvivalScores {
+    pub entries: Vec<SurvivalEntry>,
+}
+
+impl SurvivalScores {
+    // 기록 추가. 순위 안에 들면 0부터 시작하는 순위를 돌려줌
+    pub fn record(&mut self, time: f32, score: u32) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|e| time > e.time || (time == e.time && score > e.score))
+            .unwrap_or(self.entries.len());
+        if rank >= MAX_ENTRIES {
+            return None;
+        }
+        self.entries.insert(rank, SurvivalEntry { time, score });
+        self.entries.truncate(MAX_ENTRIES);
+        Some(rank)
+    }
+
+    pub fn save(&self) {
+        match save::write_json(SCORES_FILE, self) {
+            Ok(path) => info!("Survival scores saved to {}", path.display()),
+            Err(err) => error!("Failed to save survival scores: {}", err),
+        }
+    }
+}
+
+impl FromWorld for SurvivalScores {
+    fn from_world(_world: &mut World) -> Self {
+        match save::read_json(SCORES_FILE) {
+            Ok(Some(scores)) => scores,
+            Ok(None) => Self { entries: Vec::new() },
+            Err(err) => {
+                warn!("Failed to load survival scores, starting fresh: {}", err);
+                Self { entries: Vec::new() }
+            }
+        }
+    }
+}
+
+// 초를 m:ss.s 형태로
+// 0.1초 단위로 먼저 반올림해야 59.96초가 "0:60.0"이 아닌 "1:00.0"이 된다
+pub fn format_time(seconds: f32) -> String {
+    let tenths = (seconds.max(0.0) * 10.0).round() as u32;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
@@ -0,0 +1,3 @@
+pub mod particles;
+pub mod plugin;
+pub mod prefabs;
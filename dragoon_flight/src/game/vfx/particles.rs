@@ -0,0 +1,171 @@
+use std::f32::consts::TAU;
+
+use bevy::color::Mix;
+use bevy::prelude::*;
+
+// 동시에 존재할 수 있는 파티클 수 상한
+#[derive(Resource)]
+pub struct ParticleBudget {
+    pub max: usize,
+}
+
+impl Default for ParticleBudget {
+    fn default() -> Self {
+        Self { max: 1500 }
+    }
+}
+
+// 파티클 방향/속도 분산용 의사 난수 (xorshift). 연출용이므로 품질보다 속도 우선
+#[derive(Resource)]
+pub struct ParticleRng(u32);
+
+impl Default for ParticleRng {
+    fn default() -> Self {
+        Self(0x9E37_79B9)
+    }
+}
+
+impl ParticleRng {
+    fn next_f32(&mut self) -> f32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+// 방출되는 파티클 하나의 생김새와 움직임 정의
+#[derive(Debug, Clone)]
+pub struct ParticleSpec {
+    pub lifetime: (f32, f32),  // 수명 범위(초)
+    pub speed: (f32, f32),     // 초기 속력 범위(px/s)
+    pub direction: Vec2,       // 기준 방출 방향
+    pub spread: f32,           // 방출 각도 폭(라디안). TAU면 전방향
+    pub drag: f32,             // 초당 감속 비율
+    pub size: f32,             // 스프라이트 한 변 크기(px)
+    pub scale: (f32, f32),     // 수명 시작/끝 배율
+    pub color: (Color, Color), // 수명 시작/끝 색상
+}
+
+#[derive(Component)]
+pub struct Particle {
+    velocity: Vec2,
+    drag: f32,
+    age: f32,
+    lifetime: f32,
+    scale: (f32, f32),
+    color: (Color, Color),
+}
+
+// 파티클 방출기. 연속 방출(rate)과 일회성 방출(burst)을 모두 지원
+#[derive(Component)]
+pub struct ParticleEmitter {
+    pub spec: ParticleSpec,
+    pub rate: f32,             // 초당 방출 수
+    pub burst: u32,            // 다음 갱신 때 한 번에 방출할 수
+    pub duration: Option<f32>, // 남은 방출 시간. 끝나면 방출기 제거, None이면 무한
+    accumulator: f32,
+}
+
+impl ParticleEmitter {
+    pub fn continuous(spec: ParticleSpec, rate: f32) -> Self {
+        Self {
+            spec,
+            rate,
+            burst: 0,
+            duration: None,
+            accumulator: 0.0,
+        }
+    }
+
+    pub fn burst(spec: ParticleSpec, count: u32) -> Self {
+        Self {
+            spec,
+            rate: 0.0,
+            burst: count,
+            duration: Some(0.0),
+            accumulator: 0.0,
+        }
+    }
+}
+
+pub fn emit_particles(
+    time: Res<Time>,
+    budget: Res<ParticleBudget>,
+    mut rng: ResMut<ParticleRng>,
+    mut commands: Commands,
+    mut emitters: Query<(Entity, &mut ParticleEmitter, &Transform, &GlobalTransform, Has<Parent>)>,
+    particles: Query<(), With<Particle>>,
+) {
+    let dt = time.delta_secs();
+    let mut available = budget.max.saturating_sub(particles.iter().count());
+
+    for (entity, mut emitter, tf, global_tf, has_parent) in &mut emitters {
+        emitter.accumulator += emitter.rate * dt;
+        let continuous = emitter.accumulator.floor();
+        emitter.accumulator -= continuous;
+        let wanted = emitter.burst as usize + continuous as usize;
+        emitter.burst = 0;
+
+        // 예산을 초과하면 남은 만큼만 방출
+        let count = wanted.min(available);
+        available -= count;
+
+        // 방금 생성된 일회성 방출기는 아직 GlobalTransform이 전파되기 전이라 원점이다.
+        // 루트 방출기는 Transform이 곧 월드 위치이므로 그것을 쓴다
+        let origin = if has_parent { global_tf.translation() } else { tf.translation };
+        let spec = &emitter.spec;
+        let base_angle = spec.direction.to_angle();
+        for _ in 0..count {
+            let angle = base_angle + rng.range(-0.5, 0.5) * spec.spread.min(TAU);
+            let speed = rng.range(spec.speed.0, spec.speed.1);
+            let lifetime = rng.range(spec.lifetime.0, spec.lifetime.1).max(0.01);
+            commands.spawn((
+                Sprite::from_color(spec.color.0, Vec2::splat(spec.size)),
+                Transform::from_translation(origin).with_scale(Vec3::splat(spec.scale.0)),
+                Particle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    drag: spec.drag,
+                    age: 0.0,
+                    lifetime,
+                    scale: spec.scale,
+                    color: spec.color,
+                },
+            ));
+        }
+
+        if let Some(remaining) = emitter.duration.as_mut() {
+            *remaining -= dt;
+            if *remaining <= 0.0 {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+pub fn update_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut tf, mut sprite) in &mut particles {
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let t = particle.age / particle.lifetime;
+        let drag = (1.0 - particle.drag * dt).max(0.0);
+        particle.velocity *= drag;
+        tf.translation += (particle.velocity * dt).extend(0.0);
+        tf.scale = Vec3::splat(particle.scale.0.lerp(particle.scale.1, t));
+        sprite.color = particle.color.0.mix(&particle.color.1, t);
+    }
+}
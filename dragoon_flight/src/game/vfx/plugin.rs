@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use super::particles::{self, ParticleBudget, ParticleRng};
+use super::prefabs;
+use crate::game::events::GameplayEvent;
+
+pub struct VfxPlugin;
+
+impl Plugin for VfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParticleBudget>()
+            .init_resource::<ParticleRng>()
+            .add_systems(
+                Update,
+                (
+                    spawn_gameplay_vfx,
+                    particles::emit_particles,
+                    particles::update_particles,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// 게임플레이 이벤트에 맞는 일회성 이펙트 생성
+fn spawn_gameplay_vfx(mut commands: Commands, mut events: EventReader<GameplayEvent>) {
+    for event in events.read() {
+        match *event {
+            GameplayEvent::BulletFired { position } => {
+                commands.spawn(prefabs::muzzle_flash(position));
+            }
+            GameplayEvent::EnemyHit { position } | GameplayEvent::EnemyCleared { position } => {
+                commands.spawn(prefabs::impact_sparks(position));
+            }
+            GameplayEvent::EnemyKilled { position } | GameplayEvent::PlayerDied { position } => {
+                commands.spawn(prefabs::explosion(position));
+            }
+            GameplayEvent::PlayerHurt { position } => {
+                commands.spawn(prefabs::hurt_burst(position));
+            }
+        }
+    }
+}
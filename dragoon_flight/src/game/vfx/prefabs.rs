@@ -0,0 +1,95 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::particles::{ParticleEmitter, ParticleSpec};
+
+// 총알이 적에게 맞았을 때 튀는 불꽃
+pub fn impact_sparks(position: Vec2) -> impl Bundle {
+    let spec = ParticleSpec {
+        lifetime: (0.12, 0.25),
+        speed: (120.0, 260.0),
+        direction: Vec2::NEG_X,
+        spread: 2.2,
+        drag: 6.0,
+        size: 4.0,
+        scale: (1.0, 0.3),
+        color: (Color::srgb(1.0, 0.95, 0.6), Color::srgba(1.0, 0.5, 0.1, 0.0)),
+    };
+    (
+        ParticleEmitter::burst(spec, 8),
+        Transform::from_translation(position.extend(0.6)),
+    )
+}
+
+// 적 처치 시 폭발
+pub fn explosion(position: Vec2) -> impl Bundle {
+    let spec = ParticleSpec {
+        lifetime: (0.3, 0.7),
+        speed: (60.0, 240.0),
+        direction: Vec2::X,
+        spread: TAU,
+        drag: 3.0,
+        size: 8.0,
+        scale: (1.4, 0.2),
+        color: (Color::srgb(1.0, 0.85, 0.3), Color::srgba(0.6, 0.1, 0.05, 0.0)),
+    };
+    (
+        ParticleEmitter::burst(spec, 40),
+        Transform::from_translation(position.extend(0.6)),
+    )
+}
+
+// 플레이어 피격 시 튀는 파편
+pub fn hurt_burst(position: Vec2) -> impl Bundle {
+    let spec = ParticleSpec {
+        lifetime: (0.2, 0.4),
+        speed: (100.0, 220.0),
+        direction: Vec2::X,
+        spread: TAU,
+        drag: 4.0,
+        size: 5.0,
+        scale: (1.0, 0.4),
+        color: (Color::srgb(1.0, 0.3, 0.3), Color::srgba(0.5, 0.0, 0.0, 0.0)),
+    };
+    (
+        ParticleEmitter::burst(spec, 20),
+        Transform::from_translation(position.extend(0.6)),
+    )
+}
+
+// 총구 섬광
+pub fn muzzle_flash(position: Vec2) -> impl Bundle {
+    let spec = ParticleSpec {
+        lifetime: (0.04, 0.08),
+        speed: (60.0, 140.0),
+        direction: Vec2::X,
+        spread: 0.8,
+        drag: 0.0,
+        size: 3.0,
+        scale: (1.0, 0.5),
+        color: (Color::srgb(1.0, 1.0, 0.8), Color::srgba(1.0, 0.8, 0.4, 0.0)),
+    };
+    (
+        ParticleEmitter::burst(spec, 3),
+        Transform::from_translation(position.extend(0.6)),
+    )
+}
+
+// 플레이어 엔진 분사. 플레이어의 자식으로 붙여 사용
+pub fn engine_trail() -> impl Bundle {
+    let spec = ParticleSpec {
+        lifetime: (0.2, 0.35),
+        speed: (80.0, 140.0),
+        direction: Vec2::NEG_X,
+        spread: 0.4,
+        drag: 1.0,
+        size: 5.0,
+        scale: (1.0, 0.3),
+        color: (Color::srgb(1.0, 0.7, 0.3), Color::srgba(0.9, 0.2, 0.1, 0.0)),
+    };
+    (
+        ParticleEmitter::continuous(spec, 45.0),
+        Transform::from_xyz(-24.0, 0.0, -0.1),
+    )
+}
@@ -1,11 +1,59 @@
 mod game;
 
-use bevy::prelude::*;
-use game::scene_manager::plugin::ScenesPlugin;
+use bevy::{log::LogPlugin, prelude::*};
+use dragoon_engine::animation::AnimationPlugin;
+use dragoon_engine::collision::CollisionPlugin;
+use dragoon_engine::kinematics::KinematicsPlugin;
+use dragoon_engine::platform::{PlatformKind, PlatformPlugin, WindowDesc};
+use dragoon_engine::scene::SceneManagerPlugin;
+use game::audio::plugin::GameAudioPlugin;
+use game::build_info::BuildInfo;
+use game::camera::plugin::CameraEffectsPlugin;
+use game::console::plugin::ConsolePlugin;
+use game::crash::install_crash_handler;
+use game::debug::plugin::DebugOverlayPlugin;
+use game::fonts::plugin::FontsPlugin;
+use game::locale::plugin::LocalePlugin;
+use game::net::plugin::NetPlugin;
+use game::playfield::PlayfieldPlugin;
+use game::scene_manager::{plugin::ScenesPlugin, scene_ids};
+use game::settings::Settings;
+use game::vfx::plugin::VfxPlugin;
 
 fn main() {
+    let build = BuildInfo::current();
+    install_crash_handler(build.clone());
+    let settings = Settings::default();
+    // --headless: 창/렌더링 없이 실행 (서버, 자동 테스트)
+    let platform = PlatformKind::from_args(std::env::args().skip(1));
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins(platform.default_plugins().set(LogPlugin {
+            filter: settings.debug.log_filter.clone(),
+            ..Default::default()
+        }))
+        .insert_resource(settings)
+        .insert_resource(build)
+        // 엔진
+        .add_plugins(PlatformPlugin {
+            kind: platform,
+            window: WindowDesc {
+                title: "Dragoon Flight".to_string(),
+                width: 1280,
+                height: 720,
+            },
+        })
+        .add_plugins(SceneManagerPlugin { initial: scene_ids::MAIN })
+        .add_plugins((AnimationPlugin, KinematicsPlugin, CollisionPlugin))
+        // 게임
+        .add_plugins(FontsPlugin)
+        .add_plugins(LocalePlugin)
+        .add_plugins(PlayfieldPlugin)
         .add_plugins(ScenesPlugin)
+        .add_plugins(GameAudioPlugin)
+        .add_plugins(VfxPlugin)
+        .add_plugins(CameraEffectsPlugin)
+        .add_plugins(NetPlugin)
+        .add_plugins(DebugOverlayPlugin)
+        .add_plugins(ConsolePlugin)
         .run();
 }